@@ -1,5 +1,6 @@
 library;
 
 export 'src/rust/api/simple.dart';
+export 'src/rust/api/transform.dart';
 export 'src/rust/frb_generated.dart' show RustLib;
 export 'src/convex_client.dart';
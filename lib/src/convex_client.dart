@@ -44,6 +44,9 @@ import 'package:convex_flutter/src/lib/utils.dart';
 ///
 /// // Cancel subscription when done
 /// client.cancelSubscription(subscription);
+///
+/// // Close the client when the app no longer needs it
+/// await client.dispose();
 /// ```
 /// A client class for interacting with Convex backend services
 /// Implements singleton pattern to ensure only one instance exists
@@ -62,32 +65,49 @@ class ConvexClient {
   ///
   /// [deploymentUrl] - The URL of your Convex deployment
   /// [clientId] - A unique identifier for this client instance
+  /// [engineId] - Identifies this Flutter engine when several engines in the
+  /// process (e.g. an add-to-app module or a background isolate's engine)
+  /// should share one connection. Each engine passes its own id and receives
+  /// only its own subscription callbacks. Leave null for a dedicated client.
   ///
   /// Returns the singleton instance after initialization
   /// Will reuse existing instance if already initialized
   static Future<ConvexClient> init({
     required String deploymentUrl,
     required String clientId,
+    String? engineId,
   }) async {
     if (_instance == null) {
       // Initialize Rust FFI library
       await RustLib.init();
 
-      // Create new mobile client instance
-      final client = await MobileConvexClient.newInstance(
-        deploymentUrl: deploymentUrl,
-        clientId: clientId,
-      );
+      // Create new mobile client instance, or attach to the shared one
+      final MobileConvexClient client;
+      if (engineId == null) {
+        client = await MobileConvexClient.newInstance(
+          deploymentUrl: deploymentUrl,
+          clientId: clientId,
+        );
+      } else {
+        client = await MobileConvexClient.attach(
+          deploymentUrl: deploymentUrl,
+          clientId: clientId,
+          engineId: engineId,
+        );
+      }
 
       // Create singleton instance
-      _instance = ConvexClient._internal(client);
+      _instance = ConvexClient._internal(client, engineId);
     }
     return _instance!;
   }
 
   /// Private constructor to prevent direct instantiation
-  /// Takes the mobile client instance
-  ConvexClient._internal(this._client);
+  /// Takes the mobile client instance and the engine it is attached for
+  ConvexClient._internal(this._client, this.engineId);
+
+  /// The engine this client is attached for, or null for a dedicated client
+  final String? engineId;
 
   /// Executes a Convex query operation
   ///
@@ -168,4 +188,17 @@ class ConvexClient {
   void cancelSubscription(ArcSubscriptionHandle handle) {
     handle.dispose();
   }
+
+  /// Closes the client and releases the singleton instance
+  ///
+  /// In-flight operations fail with [ClientError.cancelled] and active
+  /// subscriptions stop. An attached engine is also detached from the shared
+  /// connection. Call [init] again to create a new client.
+  Future<void> dispose() async {
+    await _client.close();
+    _client.dispose();
+    if (identical(_instance, this)) {
+      _instance = null;
+    }
+  }
 }
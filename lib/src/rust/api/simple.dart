@@ -6,10 +6,11 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'transform.dart';
 part 'simple.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `connected_client`, `handle_direct_function_result`, `internal_action`, `internal_mutation`, `internal_set_auth`, `internal_subscribe`, `new`, `parse_json_args`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`, `fmt`, `from`
+// These functions are ignored because they are not marked as `pub`: `account_traffic`, `args_payload`, `args_payload_len`, `cached_result`, `cancellable`, `clear_callbacks`, `connected_client`, `display_len`, `enforce_subscription_limit`, `expire_prefetch`, `forget_prefetch`, `handle_direct_function_result`, `internal_action`, `internal_mutation`, `internal_set_auth`, `internal_subscribe`, `invalidate_prefetched`, `is_live`, `json_string_len`, `new`, `new`, `new`, `new`, `parse_json_args`, `parse_rate_limit`, `parse_retry_after`, `rearm`, `record_call`, `record_result`, `register_subscription`, `spawn_cancellable`, `touch_subscription`, `track_subscription`, `truncate_payload`, `unregister_subscription`, `with_core`, `with_rate_limit_retry`, `with_runtime`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `default`, `default`, `default`, `drop`, `drop`, `drop`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`
 
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < SubscriptionHandle >>>
 abstract class ArcSubscriptionHandle implements RustOpaqueInterface {}
@@ -42,7 +43,45 @@ abstract class MobileConvexClient implements RustOpaqueInterface {
     required Map<String, String> args,
   });
 
+  /// Attaches a Flutter engine to the client shared by all engines using the
+  /// same deployment URL and client ID, creating it on first use.
+  ///
+  /// Each engine gets its own handle, so subscription callbacks are delivered
+  /// to the engine that subscribed. The connection stays open until every
+  /// engine has detached.
+  static Future<MobileConvexClient> attach({
+    required String deploymentUrl,
+    required String clientId,
+    required String engineId,
+  }) => RustLib.instance.api.crateApiSimpleMobileConvexClientAttach(
+    deploymentUrl: deploymentUrl,
+    clientId: clientId,
+    engineId: engineId,
+  );
+
+  /// Removes the callback installed with `set_call_tap`.
+  Future<void> clearCallTap();
+
+  /// Removes the budget set with `set_data_budget`.
+  Future<void> clearDataBudget();
+
+  /// Closes this client: every in-flight query, mutation and action started
+  /// through it fails with `Cancelled`, and its subscriptions stop after
+  /// reporting `Cancelled` through `on_error`. Shared handles are also detached.
+  /// Runs automatically when the handle is disposed.
+  Future<void> close();
+
+  /// Releases this engine's attachment and drops the callbacks installed
+  /// through this handle. When an engine's last attachment is released its
+  /// subscriptions are cancelled; when no engine remains the shared client
+  /// is released. Runs automatically when the handle is dropped.
+  Future<void> detach();
+
   /// Executes a mutation on the Convex backend.
+  ///
+  /// The client resolves a mutation once its write is reflected in its own
+  /// query results, so later reads through this client, from any engine
+  /// attached to it, observe the write.
   Future<String> mutation({
     required String name,
     required Map<String, String> args,
@@ -58,15 +97,73 @@ abstract class MobileConvexClient implements RustOpaqueInterface {
     clientId: clientId,
   );
 
+  /// Warms up queries the app is likely to need next, e.g. detail pages of
+  /// visible list items, so a later `query` for them resolves from the cache.
+  ///
+  /// Queries are subscribed one at a time, yielding to other tasks between
+  /// them, and are kept live for the TTL set with `set_prefetch_ttl`, 60
+  /// seconds by default. This only paces the warm-up; there is no scheduler
+  /// priority, so once sent each warm-up is an ordinary subscription. Returns
+  /// once every query is subscribed; results fill the cache as they arrive.
+  Future<void> prefetch({required List<(String, Map<String, String>)> queries});
+
   /// Executes a query on the Convex backend.
   Future<String> query({
     required String name,
     required Map<String, String> args,
   });
 
+  /// Clears this handle's traffic stats and re-arms its data budget callback.
+  Future<void> resetTrafficStats();
+
   /// Sets authentication token for the client.
   Future<void> setAuth({String? token});
 
+  /// Installs a callback receiving every call made through this handle:
+  /// function calls and their results, subscription updates and auth
+  /// changes, for diagnosing missed or unexpected updates.
+  ///
+  /// This is a call-level tap, observed where this client meets the Convex
+  /// client; the websocket protocol messages are not exposed, so e.g. a
+  /// transition that changed no subscribed value does not show up. Other
+  /// engines' calls are not reported, and the callback is dropped when the
+  /// handle is closed or detached. Only active in debug builds; a no-op in
+  /// release builds.
+  Future<void> setCallTap({
+    required FutureOr<void> Function(TappedCall) onCall,
+  });
+
+  /// Sets a budget on the total bytes sent and received through this handle.
+  /// `on_exceeded` is called once, with the total so far, when the budget is
+  /// first exceeded, so the app can throttle heavy subscriptions; it is
+  /// re-armed by `reset_traffic_stats` or a new budget, and dropped when the
+  /// handle is closed or detached.
+  Future<void> setDataBudget({
+    required BigInt maxBytes,
+    required FutureOr<void> Function(BigInt) onExceeded,
+  });
+
+  /// Caps the number of simultaneously live subscriptions on this client,
+  /// evicting right away if more are live. When the cap is exceeded,
+  /// prefetched queries are dropped first, then the least recently updated
+  /// subscriptions, which are cancelled and reported with their id and query
+  /// name. A prefetch never evicts a regular subscription. Pass 0 to remove the cap.
+  ///
+  /// The cap covers the whole connection, shared by every attached engine,
+  /// but `on_evicted` only hears about subscriptions created through this handle.
+  Future<void> setMaxSubscriptions({
+    required int max,
+    required FutureOr<void> Function(BigInt, String) onEvicted,
+  });
+
+  /// Sets how long queries passed to `prefetch` afterwards stay subscribed
+  /// and cached, in milliseconds. Defaults to 60 seconds.
+  Future<void> setPrefetchTtl({required int ttlMs});
+
+  /// Sets how many times a rate-limited query, mutation or action is retried
+  /// after the server-indicated delay before `RateLimited` is returned. Defaults to 0.
+  Future<void> setRateLimitRetries({required int retries});
+
   /// Subscribes to real-time updates from a Convex query.
   Future<ArcSubscriptionHandle> subscribe({
     required String name,
@@ -74,12 +171,32 @@ abstract class MobileConvexClient implements RustOpaqueInterface {
     required FutureOr<void> Function(String) onUpdate,
     required FutureOr<void> Function(String, String?) onError,
   });
+
+  /// Subscribes to a Convex query, reshaping each value on the Rust side with
+  /// the given transforms before it reaches Dart.
+  ///
+  /// Fails with `InternalError` when the crate is built without the
+  /// `transforms` feature.
+  Future<ArcSubscriptionHandle> subscribeWithTransform({
+    required String name,
+    required Map<String, String> args,
+    required List<SubscriptionTransform> transforms,
+    required FutureOr<void> Function(String) onUpdate,
+    required FutureOr<void> Function(String, String?) onError,
+  });
+
+  /// Returns the traffic exchanged with the backend through this handle so
+  /// far, per function name. Engines sharing a client each see only their own.
+  Future<List<FunctionTraffic>> trafficStats();
 }
 
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SubscriptionHandle>>
 abstract class SubscriptionHandle implements RustOpaqueInterface {
-  /// Cancels the subscription by sending a cancellation signal.
+  /// Cancels the subscription by cancelling its token.
   Future<void> cancel();
+
+  /// Returns the identifier reported to the eviction callback for this subscription.
+  Future<BigInt> id();
 }
 
 abstract class QuerySubscriber {
@@ -88,6 +205,40 @@ abstract class QuerySubscriber {
   Future<void> onUpdate({required String value});
 }
 
+/// Direction of a call reported to the debug call tap.
+enum CallDirection {
+  /// Passed from this client to the Convex client, bound for the backend.
+  outgoing,
+  /// Handed back to this client by the Convex client.
+  incoming,
+  ;
+}
+
+/// What happened at the client boundary for a tapped call.
+enum CallKind {
+  /// A one-shot query was issued, with its arguments.
+  queryCalled,
+  /// A one-shot query returned a value or an error.
+  queryReturned,
+  /// A subscription was started, with its arguments.
+  subscribed,
+  /// A subscription delivered a new value.
+  subscriptionUpdated,
+  /// A subscription delivered an error.
+  subscriptionFailed,
+  /// A mutation was issued, with its arguments.
+  mutationCalled,
+  /// A mutation returned a value or an error.
+  mutationReturned,
+  /// An action was issued, with its arguments.
+  actionCalled,
+  /// An action returned a value or an error.
+  actionReturned,
+  /// The auth token was set or cleared; the token itself is redacted.
+  authSet,
+  ;
+}
+
 @freezed
 sealed class ClientError with _$ClientError implements FrbException {
   const ClientError._();
@@ -103,4 +254,98 @@ sealed class ClientError with _$ClientError implements FrbException {
   /// An unexpected server-side error from a remote Convex function.
   const factory ClientError.serverError({required String msg}) =
       ClientError_ServerError;
+
+  /// The backend throttled or shed the request; `retry_after_ms` is the
+  /// delay it asked for, when one was given.
+  const factory ClientError.rateLimited({
+    required String msg,
+    BigInt? retryAfterMs,
+  }) = ClientError_RateLimited;
+
+  /// The operation was aborted because the client was closed or disposed.
+  const factory ClientError.cancelled() = ClientError_Cancelled;
+}
+
+/// Traffic exchanged with the backend for one Convex function.
+///
+/// Sizes are those of the JSON payloads at the client boundary; websocket
+/// framing and compression are not included.
+class FunctionTraffic {
+  /// Convex function name; empty for connection-level messages such as auth.
+  final String functionName;
+  final BigInt bytesSent;
+  final BigInt bytesReceived;
+  final BigInt messagesSent;
+  final BigInt messagesReceived;
+
+  const FunctionTraffic({
+    required this.functionName,
+    required this.bytesSent,
+    required this.bytesReceived,
+    required this.messagesSent,
+    required this.messagesReceived,
+  });
+
+  @override
+  int get hashCode =>
+      functionName.hashCode ^
+      bytesSent.hashCode ^
+      bytesReceived.hashCode ^
+      messagesSent.hashCode ^
+      messagesReceived.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FunctionTraffic &&
+          runtimeType == other.runtimeType &&
+          functionName == other.functionName &&
+          bytesSent == other.bytesSent &&
+          bytesReceived == other.bytesReceived &&
+          messagesSent == other.messagesSent &&
+          messagesReceived == other.messagesReceived;
+}
+
+/// A call reported to the debug call tap.
+///
+/// Calls are observed where this client hands work to the Convex client and
+/// gets results back; the websocket protocol messages behind them are not
+/// exposed by the Convex client, so they cannot be tapped.
+class TappedCall {
+  final CallDirection direction;
+  final CallKind kind;
+  /// Convex function the call relates to; empty for auth changes.
+  final String functionName;
+  /// Length in bytes of the full JSON payload; not the size on the wire.
+  final BigInt size;
+  /// JSON payload; longer payloads are cut to at most `MAX_TAPPED_PAYLOAD`
+  /// bytes, including the trailing `…` that marks the cut.
+  final String payload;
+
+  const TappedCall({
+    required this.direction,
+    required this.kind,
+    required this.functionName,
+    required this.size,
+    required this.payload,
+  });
+
+  @override
+  int get hashCode =>
+      direction.hashCode ^
+      kind.hashCode ^
+      functionName.hashCode ^
+      size.hashCode ^
+      payload.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TappedCall &&
+          runtimeType == other.runtimeType &&
+          direction == other.direction &&
+          kind == other.kind &&
+          functionName == other.functionName &&
+          size == other.size &&
+          payload == other.payload;
 }
@@ -238,6 +238,104 @@ as String,
 }
 
 
+}
+
+/// @nodoc
+
+
+class ClientError_RateLimited extends ClientError {
+  const ClientError_RateLimited({required this.msg, this.retryAfterMs}): super._();
+  
+
+ final  String msg;
+ final  BigInt? retryAfterMs;
+
+/// Create a copy of ClientError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ClientError_RateLimitedCopyWith<ClientError_RateLimited> get copyWith => _$ClientError_RateLimitedCopyWithImpl<ClientError_RateLimited>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ClientError_RateLimited&&(identical(other.msg, msg) || other.msg == msg)&&(identical(other.retryAfterMs, retryAfterMs) || other.retryAfterMs == retryAfterMs));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,msg,retryAfterMs);
+
+@override
+String toString() {
+  return 'ClientError.rateLimited(msg: $msg, retryAfterMs: $retryAfterMs)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ClientError_RateLimitedCopyWith<$Res> implements $ClientErrorCopyWith<$Res> {
+  factory $ClientError_RateLimitedCopyWith(ClientError_RateLimited value, $Res Function(ClientError_RateLimited) _then) = _$ClientError_RateLimitedCopyWithImpl;
+@useResult
+$Res call({
+ String msg, BigInt? retryAfterMs
+});
+
+
+
+
+}
+/// @nodoc
+class _$ClientError_RateLimitedCopyWithImpl<$Res>
+    implements $ClientError_RateLimitedCopyWith<$Res> {
+  _$ClientError_RateLimitedCopyWithImpl(this._self, this._then);
+
+  final ClientError_RateLimited _self;
+  final $Res Function(ClientError_RateLimited) _then;
+
+/// Create a copy of ClientError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? msg = null,Object? retryAfterMs = freezed,}) {
+  return _then(ClientError_RateLimited(
+msg: null == msg ? _self.msg : msg // ignore: cast_nullable_to_non_nullable
+as String,retryAfterMs: freezed == retryAfterMs ? _self.retryAfterMs : retryAfterMs // ignore: cast_nullable_to_non_nullable
+as BigInt?,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ClientError_Cancelled extends ClientError {
+  const ClientError_Cancelled(): super._();
+  
+
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ClientError_Cancelled);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ClientError.cancelled()';
+}
+
+
 }
 
 // dart format on
@@ -0,0 +1,40 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'transform.freezed.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// A reshaping step applied to each subscription value on the Rust side,
+/// before it is serialized and handed to Dart.
+@freezed
+sealed class SubscriptionTransform with _$SubscriptionTransform {
+  const SubscriptionTransform._();
+
+  /// Sorts an array of objects by the value stored under `key`.
+  const factory SubscriptionTransform.sortBy({
+    required String key,
+    required bool descending,
+  }) = SubscriptionTransform_SortBy;
+
+  /// Groups an array of objects by the value stored under `key`, producing an
+  /// array of `{"key": value, "items": [...]}` in order of first appearance.
+  /// Keys keep their JSON type, so `7` and `"7"` form separate groups; a
+  /// missing key groups with `null`.
+  const factory SubscriptionTransform.groupBy({required String key}) =
+      SubscriptionTransform_GroupBy;
+
+  /// Keeps only the first `count` elements of an array.
+  const factory SubscriptionTransform.take({required int count}) =
+      SubscriptionTransform_Take;
+
+  /// Runs a plugin previously registered with [`register_transform`] by Rust
+  /// code linking this crate with the `transforms` feature.
+  const factory SubscriptionTransform.plugin({required String name}) =
+      SubscriptionTransform_Plugin;
+}
@@ -0,0 +1,311 @@
+// dart format width=80
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'transform.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$SubscriptionTransform {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SubscriptionTransform);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'SubscriptionTransform()';
+}
+
+
+}
+
+/// @nodoc
+class $SubscriptionTransformCopyWith<$Res>  {
+$SubscriptionTransformCopyWith(SubscriptionTransform _, $Res Function(SubscriptionTransform) __);
+}
+
+
+/// @nodoc
+
+
+class SubscriptionTransform_SortBy extends SubscriptionTransform {
+  const SubscriptionTransform_SortBy({required this.key, required this.descending}): super._();
+  
+
+ final  String key;
+ final  bool descending;
+
+/// Create a copy of SubscriptionTransform
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SubscriptionTransform_SortByCopyWith<SubscriptionTransform_SortBy> get copyWith => _$SubscriptionTransform_SortByCopyWithImpl<SubscriptionTransform_SortBy>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SubscriptionTransform_SortBy&&(identical(other.key, key) || other.key == key)&&(identical(other.descending, descending) || other.descending == descending));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,key,descending);
+
+@override
+String toString() {
+  return 'SubscriptionTransform.sortBy(key: $key, descending: $descending)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SubscriptionTransform_SortByCopyWith<$Res> implements $SubscriptionTransformCopyWith<$Res> {
+  factory $SubscriptionTransform_SortByCopyWith(SubscriptionTransform_SortBy value, $Res Function(SubscriptionTransform_SortBy) _then) = _$SubscriptionTransform_SortByCopyWithImpl;
+@useResult
+$Res call({
+ String key, bool descending
+});
+
+
+
+
+}
+/// @nodoc
+class _$SubscriptionTransform_SortByCopyWithImpl<$Res>
+    implements $SubscriptionTransform_SortByCopyWith<$Res> {
+  _$SubscriptionTransform_SortByCopyWithImpl(this._self, this._then);
+
+  final SubscriptionTransform_SortBy _self;
+  final $Res Function(SubscriptionTransform_SortBy) _then;
+
+/// Create a copy of SubscriptionTransform
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? key = null,Object? descending = null,}) {
+  return _then(SubscriptionTransform_SortBy(
+key: null == key ? _self.key : key // ignore: cast_nullable_to_non_nullable
+as String,descending: null == descending ? _self.descending : descending // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SubscriptionTransform_GroupBy extends SubscriptionTransform {
+  const SubscriptionTransform_GroupBy({required this.key}): super._();
+  
+
+ final  String key;
+
+/// Create a copy of SubscriptionTransform
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SubscriptionTransform_GroupByCopyWith<SubscriptionTransform_GroupBy> get copyWith => _$SubscriptionTransform_GroupByCopyWithImpl<SubscriptionTransform_GroupBy>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SubscriptionTransform_GroupBy&&(identical(other.key, key) || other.key == key));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,key);
+
+@override
+String toString() {
+  return 'SubscriptionTransform.groupBy(key: $key)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SubscriptionTransform_GroupByCopyWith<$Res> implements $SubscriptionTransformCopyWith<$Res> {
+  factory $SubscriptionTransform_GroupByCopyWith(SubscriptionTransform_GroupBy value, $Res Function(SubscriptionTransform_GroupBy) _then) = _$SubscriptionTransform_GroupByCopyWithImpl;
+@useResult
+$Res call({
+ String key
+});
+
+
+
+
+}
+/// @nodoc
+class _$SubscriptionTransform_GroupByCopyWithImpl<$Res>
+    implements $SubscriptionTransform_GroupByCopyWith<$Res> {
+  _$SubscriptionTransform_GroupByCopyWithImpl(this._self, this._then);
+
+  final SubscriptionTransform_GroupBy _self;
+  final $Res Function(SubscriptionTransform_GroupBy) _then;
+
+/// Create a copy of SubscriptionTransform
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? key = null,}) {
+  return _then(SubscriptionTransform_GroupBy(
+key: null == key ? _self.key : key // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SubscriptionTransform_Take extends SubscriptionTransform {
+  const SubscriptionTransform_Take({required this.count}): super._();
+  
+
+ final  int count;
+
+/// Create a copy of SubscriptionTransform
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SubscriptionTransform_TakeCopyWith<SubscriptionTransform_Take> get copyWith => _$SubscriptionTransform_TakeCopyWithImpl<SubscriptionTransform_Take>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SubscriptionTransform_Take&&(identical(other.count, count) || other.count == count));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,count);
+
+@override
+String toString() {
+  return 'SubscriptionTransform.take(count: $count)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SubscriptionTransform_TakeCopyWith<$Res> implements $SubscriptionTransformCopyWith<$Res> {
+  factory $SubscriptionTransform_TakeCopyWith(SubscriptionTransform_Take value, $Res Function(SubscriptionTransform_Take) _then) = _$SubscriptionTransform_TakeCopyWithImpl;
+@useResult
+$Res call({
+ int count
+});
+
+
+
+
+}
+/// @nodoc
+class _$SubscriptionTransform_TakeCopyWithImpl<$Res>
+    implements $SubscriptionTransform_TakeCopyWith<$Res> {
+  _$SubscriptionTransform_TakeCopyWithImpl(this._self, this._then);
+
+  final SubscriptionTransform_Take _self;
+  final $Res Function(SubscriptionTransform_Take) _then;
+
+/// Create a copy of SubscriptionTransform
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? count = null,}) {
+  return _then(SubscriptionTransform_Take(
+count: null == count ? _self.count : count // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SubscriptionTransform_Plugin extends SubscriptionTransform {
+  const SubscriptionTransform_Plugin({required this.name}): super._();
+  
+
+ final  String name;
+
+/// Create a copy of SubscriptionTransform
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SubscriptionTransform_PluginCopyWith<SubscriptionTransform_Plugin> get copyWith => _$SubscriptionTransform_PluginCopyWithImpl<SubscriptionTransform_Plugin>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SubscriptionTransform_Plugin&&(identical(other.name, name) || other.name == name));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,name);
+
+@override
+String toString() {
+  return 'SubscriptionTransform.plugin(name: $name)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SubscriptionTransform_PluginCopyWith<$Res> implements $SubscriptionTransformCopyWith<$Res> {
+  factory $SubscriptionTransform_PluginCopyWith(SubscriptionTransform_Plugin value, $Res Function(SubscriptionTransform_Plugin) _then) = _$SubscriptionTransform_PluginCopyWithImpl;
+@useResult
+$Res call({
+ String name
+});
+
+
+
+
+}
+/// @nodoc
+class _$SubscriptionTransform_PluginCopyWithImpl<$Res>
+    implements $SubscriptionTransform_PluginCopyWith<$Res> {
+  _$SubscriptionTransform_PluginCopyWithImpl(this._self, this._then);
+
+  final SubscriptionTransform_Plugin _self;
+  final $Res Function(SubscriptionTransform_Plugin) _then;
+
+/// Create a copy of SubscriptionTransform
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? name = null,}) {
+  return _then(SubscriptionTransform_Plugin(
+name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+// dart format on
@@ -4,6 +4,7 @@
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
 import 'api/simple.dart';
+import 'api/transform.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
@@ -62,7 +63,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.7.0';
 
   @override
-  int get rustContentHash => -1297452957;
+  int get rustContentHash => 1273692840;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -101,6 +102,28 @@ abstract class RustLibApi extends BaseApi {
     required Map<String, String> args,
   });
 
+  Future<MobileConvexClient> crateApiSimpleMobileConvexClientAttach({
+    required String deploymentUrl,
+    required String clientId,
+    required String engineId,
+  });
+
+  Future<void> crateApiSimpleMobileConvexClientClearCallTap({
+    required MobileConvexClient that,
+  });
+
+  Future<void> crateApiSimpleMobileConvexClientClearDataBudget({
+    required MobileConvexClient that,
+  });
+
+  Future<void> crateApiSimpleMobileConvexClientClose({
+    required MobileConvexClient that,
+  });
+
+  Future<void> crateApiSimpleMobileConvexClientDetach({
+    required MobileConvexClient that,
+  });
+
   Future<String> crateApiSimpleMobileConvexClientMutation({
     required MobileConvexClient that,
     required String name,
@@ -112,17 +135,53 @@ abstract class RustLibApi extends BaseApi {
     required String clientId,
   });
 
+  Future<void> crateApiSimpleMobileConvexClientPrefetch({
+    required MobileConvexClient that,
+    required List<(String, Map<String, String>)> queries,
+  });
+
   Future<String> crateApiSimpleMobileConvexClientQuery({
     required MobileConvexClient that,
     required String name,
     required Map<String, String> args,
   });
 
+  Future<void> crateApiSimpleMobileConvexClientResetTrafficStats({
+    required MobileConvexClient that,
+  });
+
   Future<void> crateApiSimpleMobileConvexClientSetAuth({
     required MobileConvexClient that,
     String? token,
   });
 
+  Future<void> crateApiSimpleMobileConvexClientSetCallTap({
+    required MobileConvexClient that,
+    required FutureOr<void> Function(TappedCall) onCall,
+  });
+
+  Future<void> crateApiSimpleMobileConvexClientSetDataBudget({
+    required MobileConvexClient that,
+    required BigInt maxBytes,
+    required FutureOr<void> Function(BigInt) onExceeded,
+  });
+
+  Future<void> crateApiSimpleMobileConvexClientSetMaxSubscriptions({
+    required MobileConvexClient that,
+    required int max,
+    required FutureOr<void> Function(BigInt, String) onEvicted,
+  });
+
+  Future<void> crateApiSimpleMobileConvexClientSetPrefetchTtl({
+    required MobileConvexClient that,
+    required int ttlMs,
+  });
+
+  Future<void> crateApiSimpleMobileConvexClientSetRateLimitRetries({
+    required MobileConvexClient that,
+    required int retries,
+  });
+
   Future<ArcSubscriptionHandle> crateApiSimpleMobileConvexClientSubscribe({
     required MobileConvexClient that,
     required String name,
@@ -131,10 +190,28 @@ abstract class RustLibApi extends BaseApi {
     required FutureOr<void> Function(String, String?) onError,
   });
 
+  Future<ArcSubscriptionHandle>
+  crateApiSimpleMobileConvexClientSubscribeWithTransform({
+    required MobileConvexClient that,
+    required String name,
+    required Map<String, String> args,
+    required List<SubscriptionTransform> transforms,
+    required FutureOr<void> Function(String) onUpdate,
+    required FutureOr<void> Function(String, String?) onError,
+  });
+
+  Future<List<FunctionTraffic>> crateApiSimpleMobileConvexClientTrafficStats({
+    required MobileConvexClient that,
+  });
+
   Future<void> crateApiSimpleSubscriptionHandleCancel({
     required SubscriptionHandle that,
   });
 
+  Future<BigInt> crateApiSimpleSubscriptionHandleId({
+    required SubscriptionHandle that,
+  });
+
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_ArcSubscriptionHandle;
 
@@ -385,6 +462,188 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "name", "args"],
       );
 
+  @override
+  Future<MobileConvexClient> crateApiSimpleMobileConvexClientAttach({
+    required String deploymentUrl,
+    required String clientId,
+    required String engineId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(deploymentUrl, serializer);
+          sse_encode_String(clientId, serializer);
+          sse_encode_String(engineId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 6,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleMobileConvexClientAttachConstMeta,
+        argValues: [deploymentUrl, clientId, engineId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleMobileConvexClientAttachConstMeta =>
+      const TaskConstMeta(
+        debugName: "MobileConvexClient_attach",
+        argNames: ["deploymentUrl", "clientId", "engineId"],
+      );
+
+  @override
+  Future<void> crateApiSimpleMobileConvexClientClearCallTap({
+    required MobileConvexClient that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 7,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleMobileConvexClientClearCallTapConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleMobileConvexClientClearCallTapConstMeta =>
+      const TaskConstMeta(
+        debugName: "MobileConvexClient_clear_call_tap",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<void> crateApiSimpleMobileConvexClientClearDataBudget({
+    required MobileConvexClient that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 8,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleMobileConvexClientClearDataBudgetConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleMobileConvexClientClearDataBudgetConstMeta =>
+      const TaskConstMeta(
+        debugName: "MobileConvexClient_clear_data_budget",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<void> crateApiSimpleMobileConvexClientClose({
+    required MobileConvexClient that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 9,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleMobileConvexClientCloseConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleMobileConvexClientCloseConstMeta =>
+      const TaskConstMeta(
+        debugName: "MobileConvexClient_close",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<void> crateApiSimpleMobileConvexClientDetach({
+    required MobileConvexClient that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 10,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleMobileConvexClientDetachConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleMobileConvexClientDetachConstMeta =>
+      const TaskConstMeta(
+        debugName: "MobileConvexClient_detach",
+        argNames: ["that"],
+      );
+
   @override
   Future<String> crateApiSimpleMobileConvexClientMutation({
     required MobileConvexClient that,
@@ -404,7 +663,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 6,
+            funcId: 11,
             port: port_,
           );
         },
@@ -439,7 +698,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 7,
+            funcId: 12,
             port: port_,
           );
         },
@@ -461,6 +720,44 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["deploymentUrl", "clientId"],
       );
 
+  @override
+  Future<void> crateApiSimpleMobileConvexClientPrefetch({
+    required MobileConvexClient that,
+    required List<(String, Map<String, String>)> queries,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
+            that,
+            serializer,
+          );
+          sse_encode_list_record_string_map_string_string(queries, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 13,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        ),
+        constMeta: kCrateApiSimpleMobileConvexClientPrefetchConstMeta,
+        argValues: [that, queries],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleMobileConvexClientPrefetchConstMeta =>
+      const TaskConstMeta(
+        debugName: "MobileConvexClient_prefetch",
+        argNames: ["that", "queries"],
+      );
+
   @override
   Future<String> crateApiSimpleMobileConvexClientQuery({
     required MobileConvexClient that,
@@ -480,7 +777,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 8,
+            funcId: 14,
             port: port_,
           );
         },
@@ -502,9 +799,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  Future<void> crateApiSimpleMobileConvexClientSetAuth({
+  Future<void> crateApiSimpleMobileConvexClientResetTrafficStats({
     required MobileConvexClient that,
-    String? token,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -514,84 +810,457 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          sse_encode_opt_String(token, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 9,
+            funcId: 15,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_client_error,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiSimpleMobileConvexClientSetAuthConstMeta,
-        argValues: [that, token],
+        constMeta: kCrateApiSimpleMobileConvexClientResetTrafficStatsConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiSimpleMobileConvexClientResetTrafficStatsConstMeta =>
+      const TaskConstMeta(
+        debugName: "MobileConvexClient_reset_traffic_stats",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<void> crateApiSimpleMobileConvexClientSetAuth({
+    required MobileConvexClient that,
+    String? token,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
+            that,
+            serializer,
+          );
+          sse_encode_opt_String(token, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 16,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        ),
+        constMeta: kCrateApiSimpleMobileConvexClientSetAuthConstMeta,
+        argValues: [that, token],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleMobileConvexClientSetAuthConstMeta =>
+      const TaskConstMeta(
+        debugName: "MobileConvexClient_set_auth",
+        argNames: ["that", "token"],
+      );
+
+  @override
+  Future<void> crateApiSimpleMobileConvexClientSetCallTap({
+    required MobileConvexClient that,
+    required FutureOr<void> Function(TappedCall) onCall,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
+            that,
+            serializer,
+          );
+          sse_encode_DartFn_Inputs_tapped_call_Output_unit_AnyhowException(
+            onCall,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 17,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleMobileConvexClientSetCallTapConstMeta,
+        argValues: [that, onCall],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleMobileConvexClientSetCallTapConstMeta =>
+      const TaskConstMeta(
+        debugName: "MobileConvexClient_set_call_tap",
+        argNames: ["that", "onCall"],
+      );
+
+  @override
+  Future<void> crateApiSimpleMobileConvexClientSetDataBudget({
+    required MobileConvexClient that,
+    required BigInt maxBytes,
+    required FutureOr<void> Function(BigInt) onExceeded,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
+            that,
+            serializer,
+          );
+          sse_encode_u_64(maxBytes, serializer);
+          sse_encode_DartFn_Inputs_u_64_Output_unit_AnyhowException(
+            onExceeded,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 18,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleMobileConvexClientSetDataBudgetConstMeta,
+        argValues: [that, maxBytes, onExceeded],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleMobileConvexClientSetDataBudgetConstMeta =>
+      const TaskConstMeta(
+        debugName: "MobileConvexClient_set_data_budget",
+        argNames: ["that", "maxBytes", "onExceeded"],
+      );
+
+  @override
+  Future<void> crateApiSimpleMobileConvexClientSetMaxSubscriptions({
+    required MobileConvexClient that,
+    required int max,
+    required FutureOr<void> Function(BigInt, String) onEvicted,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
+            that,
+            serializer,
+          );
+          sse_encode_u_32(max, serializer);
+          sse_encode_DartFn_Inputs_u_64_String_Output_unit_AnyhowException(
+            onEvicted,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 19,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiSimpleMobileConvexClientSetMaxSubscriptionsConstMeta,
+        argValues: [that, max, onEvicted],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiSimpleMobileConvexClientSetMaxSubscriptionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "MobileConvexClient_set_max_subscriptions",
+        argNames: ["that", "max", "onEvicted"],
+      );
+
+  @override
+  Future<void> crateApiSimpleMobileConvexClientSetPrefetchTtl({
+    required MobileConvexClient that,
+    required int ttlMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
+            that,
+            serializer,
+          );
+          sse_encode_u_32(ttlMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 20,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleMobileConvexClientSetPrefetchTtlConstMeta,
+        argValues: [that, ttlMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleMobileConvexClientSetPrefetchTtlConstMeta =>
+      const TaskConstMeta(
+        debugName: "MobileConvexClient_set_prefetch_ttl",
+        argNames: ["that", "ttlMs"],
+      );
+
+  @override
+  Future<void> crateApiSimpleMobileConvexClientSetRateLimitRetries({
+    required MobileConvexClient that,
+    required int retries,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
+            that,
+            serializer,
+          );
+          sse_encode_u_32(retries, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 21,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiSimpleMobileConvexClientSetRateLimitRetriesConstMeta,
+        argValues: [that, retries],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiSimpleMobileConvexClientSetRateLimitRetriesConstMeta =>
+      const TaskConstMeta(
+        debugName: "MobileConvexClient_set_rate_limit_retries",
+        argNames: ["that", "retries"],
+      );
+
+  @override
+  Future<ArcSubscriptionHandle> crateApiSimpleMobileConvexClientSubscribe({
+    required MobileConvexClient that,
+    required String name,
+    required Map<String, String> args,
+    required FutureOr<void> Function(String) onUpdate,
+    required FutureOr<void> Function(String, String?) onError,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
+            that,
+            serializer,
+          );
+          sse_encode_String(name, serializer);
+          sse_encode_Map_String_String(args, serializer);
+          sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(
+            onUpdate,
+            serializer,
+          );
+          sse_encode_DartFn_Inputs_String_opt_String_Output_unit_AnyhowException(
+            onError,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 22,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSubscriptionHandle,
+          decodeErrorData: sse_decode_client_error,
+        ),
+        constMeta: kCrateApiSimpleMobileConvexClientSubscribeConstMeta,
+        argValues: [that, name, args, onUpdate, onError],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleMobileConvexClientSubscribeConstMeta =>
+      const TaskConstMeta(
+        debugName: "MobileConvexClient_subscribe",
+        argNames: ["that", "name", "args", "onUpdate", "onError"],
+      );
+
+  @override
+  Future<ArcSubscriptionHandle>
+  crateApiSimpleMobileConvexClientSubscribeWithTransform({
+    required MobileConvexClient that,
+    required String name,
+    required Map<String, String> args,
+    required List<SubscriptionTransform> transforms,
+    required FutureOr<void> Function(String) onUpdate,
+    required FutureOr<void> Function(String, String?) onError,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
+            that,
+            serializer,
+          );
+          sse_encode_String(name, serializer);
+          sse_encode_Map_String_String(args, serializer);
+          sse_encode_list_subscription_transform(transforms, serializer);
+          sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(
+            onUpdate,
+            serializer,
+          );
+          sse_encode_DartFn_Inputs_String_opt_String_Output_unit_AnyhowException(
+            onError,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 23,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSubscriptionHandle,
+          decodeErrorData: sse_decode_client_error,
+        ),
+        constMeta:
+            kCrateApiSimpleMobileConvexClientSubscribeWithTransformConstMeta,
+        argValues: [that, name, args, transforms, onUpdate, onError],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiSimpleMobileConvexClientSubscribeWithTransformConstMeta =>
+      const TaskConstMeta(
+        debugName: "MobileConvexClient_subscribe_with_transform",
+        argNames: ["that", "name", "args", "transforms", "onUpdate", "onError"],
+      );
+
+  @override
+  Future<List<FunctionTraffic>> crateApiSimpleMobileConvexClientTrafficStats({
+    required MobileConvexClient that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 24,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_function_traffic,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleMobileConvexClientTrafficStatsConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleMobileConvexClientSetAuthConstMeta =>
+  TaskConstMeta get kCrateApiSimpleMobileConvexClientTrafficStatsConstMeta =>
       const TaskConstMeta(
-        debugName: "MobileConvexClient_set_auth",
-        argNames: ["that", "token"],
+        debugName: "MobileConvexClient_traffic_stats",
+        argNames: ["that"],
       );
 
   @override
-  Future<ArcSubscriptionHandle> crateApiSimpleMobileConvexClientSubscribe({
-    required MobileConvexClient that,
-    required String name,
-    required Map<String, String> args,
-    required FutureOr<void> Function(String) onUpdate,
-    required FutureOr<void> Function(String, String?) onError,
+  Future<void> crateApiSimpleSubscriptionHandleCancel({
+    required SubscriptionHandle that,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(
             that,
             serializer,
           );
-          sse_encode_String(name, serializer);
-          sse_encode_Map_String_String(args, serializer);
-          sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(
-            onUpdate,
-            serializer,
-          );
-          sse_encode_DartFn_Inputs_String_opt_String_Output_unit_AnyhowException(
-            onError,
-            serializer,
-          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 10,
+            funcId: 25,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSubscriptionHandle,
-          decodeErrorData: sse_decode_client_error,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiSimpleMobileConvexClientSubscribeConstMeta,
-        argValues: [that, name, args, onUpdate, onError],
+        constMeta: kCrateApiSimpleSubscriptionHandleCancelConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleMobileConvexClientSubscribeConstMeta =>
+  TaskConstMeta get kCrateApiSimpleSubscriptionHandleCancelConstMeta =>
       const TaskConstMeta(
-        debugName: "MobileConvexClient_subscribe",
-        argNames: ["that", "name", "args", "onUpdate", "onError"],
+        debugName: "SubscriptionHandle_cancel",
+        argNames: ["that"],
       );
 
   @override
-  Future<void> crateApiSimpleSubscriptionHandleCancel({
+  Future<BigInt> crateApiSimpleSubscriptionHandleId({
     required SubscriptionHandle that,
   }) {
     return handler.executeNormal(
@@ -605,24 +1274,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 11,
+            funcId: 26,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_u_64,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiSimpleSubscriptionHandleCancelConstMeta,
+        constMeta: kCrateApiSimpleSubscriptionHandleIdConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleSubscriptionHandleCancelConstMeta =>
+  TaskConstMeta get kCrateApiSimpleSubscriptionHandleIdConstMeta =>
       const TaskConstMeta(
-        debugName: "SubscriptionHandle_cancel",
+        debugName: "SubscriptionHandle_id",
         argNames: ["that"],
       );
 
@@ -697,6 +1366,112 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     };
   }
 
+  Future<void> Function(int, dynamic)
+  encode_DartFn_Inputs_tapped_call_Output_unit_AnyhowException(
+    FutureOr<void> Function(TappedCall) raw,
+  ) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_tapped_call(rawArg0);
+
+      Box<void>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_unit(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+        callId: callId,
+        ptr: output.ptr,
+        rustVecLen: output.rustVecLen,
+        dataLen: output.dataLen,
+      );
+    };
+  }
+
+  Future<void> Function(int, dynamic)
+  encode_DartFn_Inputs_u_64_Output_unit_AnyhowException(
+    FutureOr<void> Function(BigInt) raw,
+  ) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_u_64(rawArg0);
+
+      Box<void>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_unit(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+        callId: callId,
+        ptr: output.ptr,
+        rustVecLen: output.rustVecLen,
+        dataLen: output.dataLen,
+      );
+    };
+  }
+
+  Future<void> Function(int, dynamic, dynamic)
+  encode_DartFn_Inputs_u_64_String_Output_unit_AnyhowException(
+    FutureOr<void> Function(BigInt, String) raw,
+  ) {
+    return (callId, rawArg0, rawArg1) async {
+      final arg0 = dco_decode_u_64(rawArg0);
+      final arg1 = dco_decode_String(rawArg1);
+
+      Box<void>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0, arg1));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_unit(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+        callId: callId,
+        ptr: output.ptr,
+        rustVecLen: output.rustVecLen,
+        dataLen: output.dataLen,
+      );
+    };
+  }
+
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_ArcSubscriptionHandle =>
       wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcSubscriptionHandle;
@@ -844,6 +1619,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError('');
   }
 
+  @protected
+  FutureOr<void> Function(TappedCall)
+  dco_decode_DartFn_Inputs_tapped_call_Output_unit_AnyhowException(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  FutureOr<void> Function(BigInt)
+  dco_decode_DartFn_Inputs_u_64_Output_unit_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  FutureOr<void> Function(BigInt, String)
+  dco_decode_DartFn_Inputs_u_64_String_Output_unit_AnyhowException(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
   @protected
   Object dco_decode_DartOpaque(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -919,6 +1719,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError();
   }
 
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  CallDirection dco_decode_call_direction(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CallDirection.values[raw as int];
+  }
+
+  @protected
+  CallKind dco_decode_call_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CallKind.values[raw as int];
+  }
+
   @protected
   ClientError dco_decode_client_error(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -929,35 +1753,100 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         return ClientError_ConvexError(data: dco_decode_String(raw[1]));
       case 2:
         return ClientError_ServerError(msg: dco_decode_String(raw[1]));
+      case 3:
+        return ClientError_RateLimited(
+          msg: dco_decode_String(raw[1]),
+          retryAfterMs: dco_decode_opt_box_autoadd_u_64(raw[2]),
+        );
+      case 4:
+        return ClientError_Cancelled();
       default:
         throw Exception("unreachable");
     }
   }
 
+  @protected
+  FunctionTraffic dco_decode_function_traffic(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return FunctionTraffic(
+      functionName: dco_decode_String(arr[0]),
+      bytesSent: dco_decode_u_64(arr[1]),
+      bytesReceived: dco_decode_u_64(arr[2]),
+      messagesSent: dco_decode_u_64(arr[3]),
+      messagesReceived: dco_decode_u_64(arr[4]),
+    );
+  }
+
   @protected
   PlatformInt64 dco_decode_isize(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return dcoDecodeI64(raw);
   }
 
+  @protected
+  List<FunctionTraffic> dco_decode_list_function_traffic(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_function_traffic).toList();
+  }
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as Uint8List;
   }
 
+  @protected
+  List<(String, Map<String, String>)>
+  dco_decode_list_record_string_map_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_record_string_map_string_string)
+        .toList();
+  }
+
   @protected
   List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
   }
 
+  @protected
+  List<SubscriptionTransform> dco_decode_list_subscription_transform(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_subscription_transform)
+        .toList();
+  }
+
   @protected
   String? dco_decode_opt_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw == null ? null : dco_decode_String(raw);
   }
 
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  (String, Map<String, String>) dco_decode_record_string_map_string_string(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_Map_String_String(arr[1]));
+  }
+
   @protected
   (String, String) dco_decode_record_string_string(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -968,6 +1857,53 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (dco_decode_String(arr[0]), dco_decode_String(arr[1]));
   }
 
+  @protected
+  SubscriptionTransform dco_decode_subscription_transform(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return SubscriptionTransform_SortBy(
+          key: dco_decode_String(raw[1]),
+          descending: dco_decode_bool(raw[2]),
+        );
+      case 1:
+        return SubscriptionTransform_GroupBy(key: dco_decode_String(raw[1]));
+      case 2:
+        return SubscriptionTransform_Take(count: dco_decode_u_32(raw[1]));
+      case 3:
+        return SubscriptionTransform_Plugin(name: dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  TappedCall dco_decode_tapped_call(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return TappedCall(
+      direction: dco_decode_call_direction(arr[0]),
+      kind: dco_decode_call_kind(arr[1]),
+      functionName: dco_decode_String(arr[2]),
+      size: dco_decode_u_64(arr[3]),
+      payload: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
   @protected
   int dco_decode_u_8(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1178,10 +2114,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  CallDirection sse_decode_call_direction(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return CallDirection.values[inner];
+  }
+
+  @protected
+  CallKind sse_decode_call_kind(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    var inner = sse_decode_i_32(deserializer);
+    return CallKind.values[inner];
   }
 
   @protected
@@ -1199,17 +2161,57 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case 2:
         var var_msg = sse_decode_String(deserializer);
         return ClientError_ServerError(msg: var_msg);
+      case 3:
+        var var_msg = sse_decode_String(deserializer);
+        var var_retryAfterMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+        return ClientError_RateLimited(
+          msg: var_msg,
+          retryAfterMs: var_retryAfterMs,
+        );
+      case 4:
+        return ClientError_Cancelled();
       default:
         throw UnimplementedError('');
     }
   }
 
+  @protected
+  FunctionTraffic sse_decode_function_traffic(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_functionName = sse_decode_String(deserializer);
+    var var_bytesSent = sse_decode_u_64(deserializer);
+    var var_bytesReceived = sse_decode_u_64(deserializer);
+    var var_messagesSent = sse_decode_u_64(deserializer);
+    var var_messagesReceived = sse_decode_u_64(deserializer);
+    return FunctionTraffic(
+      functionName: var_functionName,
+      bytesSent: var_bytesSent,
+      bytesReceived: var_bytesReceived,
+      messagesSent: var_messagesSent,
+      messagesReceived: var_messagesReceived,
+    );
+  }
+
   @protected
   PlatformInt64 sse_decode_isize(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return deserializer.buffer.getPlatformInt64();
   }
 
+  @protected
+  List<FunctionTraffic> sse_decode_list_function_traffic(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FunctionTraffic>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_function_traffic(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1217,6 +2219,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getUint8List(len_);
   }
 
+  @protected
+  List<(String, Map<String, String>)>
+  sse_decode_list_record_string_map_string_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, Map<String, String>)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_map_string_string(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<(String, String)> sse_decode_list_record_string_string(
     SseDeserializer deserializer,
@@ -1231,6 +2248,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<SubscriptionTransform> sse_decode_list_subscription_transform(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SubscriptionTransform>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_subscription_transform(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1242,6 +2273,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  (String, Map<String, String>) sse_decode_record_string_map_string_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_Map_String_String(deserializer);
+    return (var_field0, var_field1);
+  }
+
   @protected
   (String, String) sse_decode_record_string_string(
     SseDeserializer deserializer,
@@ -1252,6 +2304,64 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (var_field0, var_field1);
   }
 
+  @protected
+  SubscriptionTransform sse_decode_subscription_transform(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_key = sse_decode_String(deserializer);
+        var var_descending = sse_decode_bool(deserializer);
+        return SubscriptionTransform_SortBy(
+          key: var_key,
+          descending: var_descending,
+        );
+      case 1:
+        var var_key = sse_decode_String(deserializer);
+        return SubscriptionTransform_GroupBy(key: var_key);
+      case 2:
+        var var_count = sse_decode_u_32(deserializer);
+        return SubscriptionTransform_Take(count: var_count);
+      case 3:
+        var var_name = sse_decode_String(deserializer);
+        return SubscriptionTransform_Plugin(name: var_name);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  TappedCall sse_decode_tapped_call(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_direction = sse_decode_call_direction(deserializer);
+    var var_kind = sse_decode_call_kind(deserializer);
+    var var_functionName = sse_decode_String(deserializer);
+    var var_size = sse_decode_u_64(deserializer);
+    var var_payload = sse_decode_String(deserializer);
+    return TappedCall(
+      direction: var_direction,
+      kind: var_kind,
+      functionName: var_functionName,
+      size: var_size,
+      payload: var_payload,
+    );
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
   @protected
   int sse_decode_u_8(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1275,12 +2385,6 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getInt32();
   }
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
   @protected
   void sse_encode_AnyhowException(
     AnyhowException self,
@@ -1431,6 +2535,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_DartFn_Inputs_tapped_call_Output_unit_AnyhowException(
+    FutureOr<void> Function(TappedCall) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+      encode_DartFn_Inputs_tapped_call_Output_unit_AnyhowException(self),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_DartFn_Inputs_u_64_Output_unit_AnyhowException(
+    FutureOr<void> Function(BigInt) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+      encode_DartFn_Inputs_u_64_Output_unit_AnyhowException(self),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_DartFn_Inputs_u_64_String_Output_unit_AnyhowException(
+    FutureOr<void> Function(BigInt, String) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+      encode_DartFn_Inputs_u_64_String_Output_unit_AnyhowException(self),
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_DartOpaque(Object self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1529,6 +2669,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_call_direction(CallDirection self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_call_kind(CallKind self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_client_error(ClientError self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1542,15 +2706,49 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case ClientError_ServerError(msg: final msg):
         sse_encode_i_32(2, serializer);
         sse_encode_String(msg, serializer);
+      case ClientError_RateLimited(
+        msg: final msg,
+        retryAfterMs: final retryAfterMs,
+      ):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(msg, serializer);
+        sse_encode_opt_box_autoadd_u_64(retryAfterMs, serializer);
+      case ClientError_Cancelled():
+        sse_encode_i_32(4, serializer);
     }
   }
 
+  @protected
+  void sse_encode_function_traffic(
+    FunctionTraffic self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.functionName, serializer);
+    sse_encode_u_64(self.bytesSent, serializer);
+    sse_encode_u_64(self.bytesReceived, serializer);
+    sse_encode_u_64(self.messagesSent, serializer);
+    sse_encode_u_64(self.messagesReceived, serializer);
+  }
+
   @protected
   void sse_encode_isize(PlatformInt64 self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putPlatformInt64(self);
   }
 
+  @protected
+  void sse_encode_list_function_traffic(
+    List<FunctionTraffic> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_function_traffic(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
@@ -1561,6 +2759,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putUint8List(self);
   }
 
+  @protected
+  void sse_encode_list_record_string_map_string_string(
+    List<(String, Map<String, String>)> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_map_string_string(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_record_string_string(
     List<(String, String)> self,
@@ -1573,6 +2783,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_subscription_transform(
+    List<SubscriptionTransform> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_subscription_transform(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1583,6 +2805,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_record_string_map_string_string(
+    (String, Map<String, String>) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.$1, serializer);
+    sse_encode_Map_String_String(self.$2, serializer);
+  }
+
   @protected
   void sse_encode_record_string_string(
     (String, String) self,
@@ -1593,6 +2835,54 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.$2, serializer);
   }
 
+  @protected
+  void sse_encode_subscription_transform(
+    SubscriptionTransform self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case SubscriptionTransform_SortBy(
+        key: final key,
+        descending: final descending,
+      ):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(key, serializer);
+        sse_encode_bool(descending, serializer);
+      case SubscriptionTransform_GroupBy(key: final key):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(key, serializer);
+      case SubscriptionTransform_Take(count: final count):
+        sse_encode_i_32(2, serializer);
+        sse_encode_u_32(count, serializer);
+      case SubscriptionTransform_Plugin(name: final name):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(name, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_tapped_call(TappedCall self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_call_direction(self.direction, serializer);
+    sse_encode_call_kind(self.kind, serializer);
+    sse_encode_String(self.functionName, serializer);
+    sse_encode_u_64(self.size, serializer);
+    sse_encode_String(self.payload, serializer);
+  }
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint32(self);
+  }
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
+  }
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1615,12 +2905,6 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putInt32(self);
   }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
 }
 
 @sealed
@@ -1766,7 +3050,33 @@ class MobileConvexClientImpl extends RustOpaque implements MobileConvexClient {
     args: args,
   );
 
+  /// Removes the callback installed with `set_call_tap`.
+  Future<void> clearCallTap() => RustLib.instance.api
+      .crateApiSimpleMobileConvexClientClearCallTap(that: this);
+
+  /// Removes the budget set with `set_data_budget`.
+  Future<void> clearDataBudget() => RustLib.instance.api
+      .crateApiSimpleMobileConvexClientClearDataBudget(that: this);
+
+  /// Closes this client: every in-flight query, mutation and action started
+  /// through it fails with `Cancelled`, and its subscriptions stop after
+  /// reporting `Cancelled` through `on_error`. Shared handles are also detached.
+  /// Runs automatically when the handle is disposed.
+  Future<void> close() =>
+      RustLib.instance.api.crateApiSimpleMobileConvexClientClose(that: this);
+
+  /// Releases this engine's attachment and drops the callbacks installed
+  /// through this handle. When an engine's last attachment is released its
+  /// subscriptions are cancelled; when no engine remains the shared client
+  /// is released. Runs automatically when the handle is dropped.
+  Future<void> detach() =>
+      RustLib.instance.api.crateApiSimpleMobileConvexClientDetach(that: this);
+
   /// Executes a mutation on the Convex backend.
+  ///
+  /// The client resolves a mutation once its write is reflected in its own
+  /// query results, so later reads through this client, from any engine
+  /// attached to it, observe the write.
   Future<String> mutation({
     required String name,
     required Map<String, String> args,
@@ -1776,6 +3086,21 @@ class MobileConvexClientImpl extends RustOpaque implements MobileConvexClient {
     args: args,
   );
 
+  /// Warms up queries the app is likely to need next, e.g. detail pages of
+  /// visible list items, so a later `query` for them resolves from the cache.
+  ///
+  /// Queries are subscribed one at a time, yielding to other tasks between
+  /// them, and are kept live for the TTL set with `set_prefetch_ttl`, 60
+  /// seconds by default. This only paces the warm-up; there is no scheduler
+  /// priority, so once sent each warm-up is an ordinary subscription. Returns
+  /// once every query is subscribed; results fill the cache as they arrive.
+  Future<void> prefetch({
+    required List<(String, Map<String, String>)> queries,
+  }) => RustLib.instance.api.crateApiSimpleMobileConvexClientPrefetch(
+    that: this,
+    queries: queries,
+  );
+
   /// Executes a query on the Convex backend.
   Future<String> query({
     required String name,
@@ -1786,10 +3111,76 @@ class MobileConvexClientImpl extends RustOpaque implements MobileConvexClient {
     args: args,
   );
 
+  /// Clears this handle's traffic stats and re-arms its data budget callback.
+  Future<void> resetTrafficStats() => RustLib.instance.api
+      .crateApiSimpleMobileConvexClientResetTrafficStats(that: this);
+
   /// Sets authentication token for the client.
   Future<void> setAuth({String? token}) => RustLib.instance.api
       .crateApiSimpleMobileConvexClientSetAuth(that: this, token: token);
 
+  /// Installs a callback receiving every call made through this handle:
+  /// function calls and their results, subscription updates and auth
+  /// changes, for diagnosing missed or unexpected updates.
+  ///
+  /// This is a call-level tap, observed where this client meets the Convex
+  /// client; the websocket protocol messages are not exposed, so e.g. a
+  /// transition that changed no subscribed value does not show up. Other
+  /// engines' calls are not reported, and the callback is dropped when the
+  /// handle is closed or detached. Only active in debug builds; a no-op in
+  /// release builds.
+  Future<void> setCallTap({
+    required FutureOr<void> Function(TappedCall) onCall,
+  }) => RustLib.instance.api.crateApiSimpleMobileConvexClientSetCallTap(
+    that: this,
+    onCall: onCall,
+  );
+
+  /// Sets a budget on the total bytes sent and received through this handle.
+  /// `on_exceeded` is called once, with the total so far, when the budget is
+  /// first exceeded, so the app can throttle heavy subscriptions; it is
+  /// re-armed by `reset_traffic_stats` or a new budget, and dropped when the
+  /// handle is closed or detached.
+  Future<void> setDataBudget({
+    required BigInt maxBytes,
+    required FutureOr<void> Function(BigInt) onExceeded,
+  }) => RustLib.instance.api.crateApiSimpleMobileConvexClientSetDataBudget(
+    that: this,
+    maxBytes: maxBytes,
+    onExceeded: onExceeded,
+  );
+
+  /// Caps the number of simultaneously live subscriptions on this client,
+  /// evicting right away if more are live. When the cap is exceeded,
+  /// prefetched queries are dropped first, then the least recently updated
+  /// subscriptions, which are cancelled and reported with their id and query
+  /// name. A prefetch never evicts a regular subscription. Pass 0 to remove the cap.
+  ///
+  /// The cap covers the whole connection, shared by every attached engine,
+  /// but `on_evicted` only hears about subscriptions created through this handle.
+  Future<void> setMaxSubscriptions({
+    required int max,
+    required FutureOr<void> Function(BigInt, String) onEvicted,
+  }) => RustLib.instance.api
+      .crateApiSimpleMobileConvexClientSetMaxSubscriptions(
+        that: this,
+        max: max,
+        onEvicted: onEvicted,
+      );
+
+  /// Sets how long queries passed to `prefetch` afterwards stay subscribed
+  /// and cached, in milliseconds. Defaults to 60 seconds.
+  Future<void> setPrefetchTtl({required int ttlMs}) => RustLib.instance.api
+      .crateApiSimpleMobileConvexClientSetPrefetchTtl(that: this, ttlMs: ttlMs);
+
+  /// Sets how many times a rate-limited query, mutation or action is retried
+  /// after the server-indicated delay before `RateLimited` is returned. Defaults to 0.
+  Future<void> setRateLimitRetries({required int retries}) =>
+      RustLib.instance.api.crateApiSimpleMobileConvexClientSetRateLimitRetries(
+        that: this,
+        retries: retries,
+      );
+
   /// Subscribes to real-time updates from a Convex query.
   Future<ArcSubscriptionHandle> subscribe({
     required String name,
@@ -1803,6 +3194,32 @@ class MobileConvexClientImpl extends RustOpaque implements MobileConvexClient {
     onUpdate: onUpdate,
     onError: onError,
   );
+
+  /// Subscribes to a Convex query, reshaping each value on the Rust side with
+  /// the given transforms before it reaches Dart.
+  ///
+  /// Fails with `InternalError` when the crate is built without the
+  /// `transforms` feature.
+  Future<ArcSubscriptionHandle> subscribeWithTransform({
+    required String name,
+    required Map<String, String> args,
+    required List<SubscriptionTransform> transforms,
+    required FutureOr<void> Function(String) onUpdate,
+    required FutureOr<void> Function(String, String?) onError,
+  }) => RustLib.instance.api
+      .crateApiSimpleMobileConvexClientSubscribeWithTransform(
+        that: this,
+        name: name,
+        args: args,
+        transforms: transforms,
+        onUpdate: onUpdate,
+        onError: onError,
+      );
+
+  /// Returns the traffic exchanged with the backend through this handle so
+  /// far, per function name. Engines sharing a client each see only their own.
+  Future<List<FunctionTraffic>> trafficStats() => RustLib.instance.api
+      .crateApiSimpleMobileConvexClientTrafficStats(that: this);
 }
 
 @sealed
@@ -1829,7 +3246,11 @@ class SubscriptionHandleImpl extends RustOpaque implements SubscriptionHandle {
             .rust_arc_decrement_strong_count_SubscriptionHandlePtr,
   );
 
-  /// Cancels the subscription by sending a cancellation signal.
+  /// Cancels the subscription by cancelling its token.
   Future<void> cancel() =>
       RustLib.instance.api.crateApiSimpleSubscriptionHandleCancel(that: this);
+
+  /// Returns the identifier reported to the eviction callback for this subscription.
+  Future<BigInt> id() =>
+      RustLib.instance.api.crateApiSimpleSubscriptionHandleId(that: this);
 }
@@ -7,6 +7,7 @@
 // ignore_for_file: argument_type_not_assignable
 
 import 'api/simple.dart';
+import 'api/transform.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
@@ -107,6 +108,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  FutureOr<void> Function(TappedCall)
+  dco_decode_DartFn_Inputs_tapped_call_Output_unit_AnyhowException(dynamic raw);
+
+  @protected
+  FutureOr<void> Function(BigInt)
+  dco_decode_DartFn_Inputs_u_64_Output_unit_AnyhowException(dynamic raw);
+
+  @protected
+  FutureOr<void> Function(BigInt, String)
+  dco_decode_DartFn_Inputs_u_64_String_Output_unit_AnyhowException(dynamic raw);
+
   @protected
   Object dco_decode_DartOpaque(dynamic raw);
 
@@ -149,24 +162,71 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   QuerySubscriber dco_decode_TraitDef_QuerySubscriber(dynamic raw);
 
+  @protected
+  bool dco_decode_bool(dynamic raw);
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  CallDirection dco_decode_call_direction(dynamic raw);
+
+  @protected
+  CallKind dco_decode_call_kind(dynamic raw);
+
   @protected
   ClientError dco_decode_client_error(dynamic raw);
 
+  @protected
+  FunctionTraffic dco_decode_function_traffic(dynamic raw);
+
   @protected
   PlatformInt64 dco_decode_isize(dynamic raw);
 
+  @protected
+  List<FunctionTraffic> dco_decode_list_function_traffic(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  List<(String, Map<String, String>)>
+  dco_decode_list_record_string_map_string_string(dynamic raw);
+
   @protected
   List<(String, String)> dco_decode_list_record_string_string(dynamic raw);
 
+  @protected
+  List<SubscriptionTransform> dco_decode_list_subscription_transform(
+    dynamic raw,
+  );
+
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  (String, Map<String, String>) dco_decode_record_string_map_string_string(
+    dynamic raw,
+  );
+
   @protected
   (String, String) dco_decode_record_string_string(dynamic raw);
 
+  @protected
+  SubscriptionTransform dco_decode_subscription_transform(dynamic raw);
+
+  @protected
+  TappedCall dco_decode_tapped_call(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
   @protected
   int dco_decode_u_8(dynamic raw);
 
@@ -274,28 +334,79 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  CallDirection sse_decode_call_direction(SseDeserializer deserializer);
+
+  @protected
+  CallKind sse_decode_call_kind(SseDeserializer deserializer);
+
   @protected
   ClientError sse_decode_client_error(SseDeserializer deserializer);
 
+  @protected
+  FunctionTraffic sse_decode_function_traffic(SseDeserializer deserializer);
+
   @protected
   PlatformInt64 sse_decode_isize(SseDeserializer deserializer);
 
+  @protected
+  List<FunctionTraffic> sse_decode_list_function_traffic(
+    SseDeserializer deserializer,
+  );
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
+  @protected
+  List<(String, Map<String, String>)>
+  sse_decode_list_record_string_map_string_string(SseDeserializer deserializer);
+
   @protected
   List<(String, String)> sse_decode_list_record_string_string(
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<SubscriptionTransform> sse_decode_list_subscription_transform(
+    SseDeserializer deserializer,
+  );
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer);
 
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  (String, Map<String, String>) sse_decode_record_string_map_string_string(
+    SseDeserializer deserializer,
+  );
+
   @protected
   (String, String) sse_decode_record_string_string(
     SseDeserializer deserializer,
   );
 
+  @protected
+  SubscriptionTransform sse_decode_subscription_transform(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TappedCall sse_decode_tapped_call(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_8(SseDeserializer deserializer);
 
@@ -308,9 +419,6 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   int sse_decode_i_32(SseDeserializer deserializer);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
-
   @protected
   void sse_encode_AnyhowException(
     AnyhowException self,
@@ -392,6 +500,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_DartFn_Inputs_tapped_call_Output_unit_AnyhowException(
+    FutureOr<void> Function(TappedCall) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_DartFn_Inputs_u_64_Output_unit_AnyhowException(
+    FutureOr<void> Function(BigInt) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_DartFn_Inputs_u_64_String_Output_unit_AnyhowException(
+    FutureOr<void> Function(BigInt, String) self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_DartOpaque(Object self, SseSerializer serializer);
 
@@ -439,33 +565,93 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_call_direction(CallDirection self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_call_kind(CallKind self, SseSerializer serializer);
+
   @protected
   void sse_encode_client_error(ClientError self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_function_traffic(
+    FunctionTraffic self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_isize(PlatformInt64 self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_function_traffic(
+    List<FunctionTraffic> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_record_string_map_string_string(
+    List<(String, Map<String, String>)> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_record_string_string(
     List<(String, String)> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_subscription_transform(
+    List<SubscriptionTransform> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_record_string_map_string_string(
+    (String, Map<String, String>) self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_record_string_string(
     (String, String) self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_subscription_transform(
+    SubscriptionTransform self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_tapped_call(TappedCall self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer);
 
@@ -477,9 +663,6 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
 
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
 }
 
 // Section: wire_class
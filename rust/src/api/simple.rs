@@ -1,6 +1,11 @@
 use std::{
     collections::{BTreeMap, HashMap},
-    sync::Arc,
+    future::Future,
+    sync::{
+        atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering},
+        Arc, Weak,
+    },
+    time::{Duration, Instant},
 };
 
 #[cfg(debug_assertions)]
@@ -10,7 +15,6 @@ use convex::{
     ConvexClient, ConvexClientBuilder, FunctionResult, Value, // Convex client and result types
 };
 use futures::{
-    channel::oneshot::{self, Sender},
     pin_mut,
     select_biased,
     FutureExt,
@@ -19,9 +23,21 @@ use futures::{
 use log::debug; // Logging for debugging purposes
 #[cfg(debug_assertions)]
 use log::LevelFilter;
+use once_cell::sync::Lazy;
 use parking_lot::Mutex;
+use tokio::runtime::{Handle, Runtime};
+use tokio_util::sync::CancellationToken;
 use flutter_rust_bridge::{frb, DartFnFuture};
 
+#[cfg(feature = "transforms")]
+use crate::api::transform::apply_transforms;
+use crate::api::transform::SubscriptionTransform;
+
+/// Maps or filters a subscription value before it is serialized for Dart.
+/// `Ok(None)` drops the update; `Err` is reported through `on_error`.
+type ValueMapper =
+    Arc<dyn Fn(serde_json::Value) -> Result<Option<serde_json::Value>, String> + Send + Sync>;
+
 // Custom error type for Convex client operations, exposed to Dart.
 #[derive(Debug, thiserror::Error)]
 #[frb]
@@ -35,6 +51,13 @@ pub enum ClientError {
     /// An unexpected server-side error from a remote Convex function.
     #[error("ServerError: {msg}")]
     ServerError { msg: String },
+    /// The backend throttled or shed the request; `retry_after_ms` is the
+    /// delay it asked for, when one was given.
+    #[error("RateLimited: {msg}")]
+    RateLimited { msg: String, retry_after_ms: Option<u64> },
+    /// The operation was aborted because the client was closed or disposed.
+    #[error("Cancelled")]
+    Cancelled,
 }
 
 impl From<anyhow::Error> for ClientError {
@@ -43,6 +66,92 @@ impl From<anyhow::Error> for ClientError {
     }
 }
 
+/// Direction of a call reported to the debug call tap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[frb]
+pub enum CallDirection {
+    /// Passed from this client to the Convex client, bound for the backend.
+    Outgoing,
+    /// Handed back to this client by the Convex client.
+    Incoming,
+}
+
+/// What happened at the client boundary for a tapped call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[frb]
+pub enum CallKind {
+    /// A one-shot query was issued, with its arguments.
+    QueryCalled,
+    /// A one-shot query returned a value or an error.
+    QueryReturned,
+    /// A subscription was started, with its arguments.
+    Subscribed,
+    /// A subscription delivered a new value.
+    SubscriptionUpdated,
+    /// A subscription delivered an error.
+    SubscriptionFailed,
+    /// A mutation was issued, with its arguments.
+    MutationCalled,
+    /// A mutation returned a value or an error.
+    MutationReturned,
+    /// An action was issued, with its arguments.
+    ActionCalled,
+    /// An action returned a value or an error.
+    ActionReturned,
+    /// The auth token was set or cleared; the token itself is redacted.
+    AuthSet,
+}
+
+/// A call reported to the debug call tap.
+///
+/// Calls are observed where this client hands work to the Convex client and
+/// gets results back; the websocket protocol messages behind them are not
+/// exposed by the Convex client, so they cannot be tapped.
+#[frb]
+pub struct TappedCall {
+    pub direction: CallDirection,
+    pub kind: CallKind,
+    /// Convex function the call relates to; empty for auth changes.
+    pub function_name: String,
+    /// Length in bytes of the full JSON payload; not the size on the wire.
+    pub size: u64,
+    /// JSON payload; longer payloads are cut to at most `MAX_TAPPED_PAYLOAD`
+    /// bytes, including the trailing `…` that marks the cut.
+    pub payload: String,
+}
+
+/// Traffic exchanged with the backend for one Convex function.
+///
+/// Sizes are those of the JSON payloads at the client boundary; websocket
+/// framing and compression are not included.
+#[derive(Debug, Clone, Default)]
+#[frb]
+pub struct FunctionTraffic {
+    /// Convex function name; empty for connection-level messages such as auth.
+    pub function_name: String,
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+    pub messages_sent: u64,
+    pub messages_received: u64,
+}
+
+/// Traffic stats accumulated by a client, with a running total for the data budget.
+#[derive(Default)]
+struct TrafficStats {
+    functions: HashMap<String, FunctionTraffic>, // Traffic per function name
+    total_bytes: u64,                            // Bytes sent and received across all functions
+}
+
+/// A data budget set with `set_data_budget`.
+struct DataBudget {
+    max_bytes: u64, // Total bytes allowed
+    on_exceeded: Arc<dyn Fn(u64) -> DartFnFuture<()> + Send + Sync>, // Called once when exceeded
+    notified: bool, // Whether the callback already ran
+}
+
+#[cfg(debug_assertions)]
+type CallTap = Arc<dyn Fn(TappedCall) -> DartFnFuture<()> + Send + Sync>;
+
 /// Trait defining the interface for handling subscription updates.
 // Not directly exposed to Dart, used internally by subscribers.
 pub trait QuerySubscriber: Send + Sync {
@@ -66,25 +175,79 @@ impl QuerySubscriber for CallbackSubscriber {
     }
 }
 
+/// Subscriber that stores the latest value of a prefetched query in the result cache.
+///
+/// Each value is stamped with the write generation read before the
+/// subscription loop started waiting for it, so a value that was already on
+/// its way when a mutation resolved carries the old generation and is never
+/// served. Values arriving while a write resolves are dropped too; the cache
+/// refills on the following update.
+struct CacheSubscriber {
+    slot: CacheSlot,                 // Cache slot shared with the PrefetchEntry
+    write_generation: Arc<AtomicU64>, // The client's current write generation
+    armed: AtomicU64,                // Generation read before waiting for the next value
+}
+
+impl CacheSubscriber {
+    fn new(slot: CacheSlot, write_generation: Arc<AtomicU64>) -> CacheSubscriber {
+        let armed = AtomicU64::new(write_generation.load(Ordering::Acquire));
+        CacheSubscriber {
+            slot,
+            write_generation,
+            armed,
+        }
+    }
+
+    /// Returns the stamp for the value just received and re-arms for the next one.
+    fn rearm(&self) -> u64 {
+        let current = self.write_generation.load(Ordering::Acquire);
+        self.armed.swap(current, Ordering::AcqRel)
+    }
+}
+
+impl QuerySubscriber for CacheSubscriber {
+    fn on_update(&self, value: String) {
+        let generation = self.rearm();
+        *self.slot.lock() = Some((generation, value));
+    }
+
+    fn on_error(&self, _message: String, _value: Option<String>) {
+        // Let one-shot queries go to the server and surface the error themselves.
+        self.rearm();
+        *self.slot.lock() = None;
+    }
+}
+
 /// Opaque type for Dart, representing a subscription handle with cancellation.
 #[frb(opaque)]
 pub struct SubscriptionHandle {
-    cancel_sender: Mutex<Option<Sender<()>>>, // Sender to cancel the subscription
+    id: u64,                         // Identifier, unique within the client
+    cancel_token: CancellationToken, // Token cancelling the subscription loop
 }
 
 impl SubscriptionHandle {
-    fn new(cancel_sender: Sender<()>) -> Self {
-        SubscriptionHandle {
-            cancel_sender: Mutex::new(Some(cancel_sender)),
-        }
+    fn new(id: u64, cancel_token: CancellationToken) -> Self {
+        SubscriptionHandle { id, cancel_token }
+    }
+
+    /// Returns the identifier reported to the eviction callback for this subscription.
+    #[frb]
+    pub fn id(&self) -> u64 {
+        self.id
     }
 
-    /// Cancels the subscription by sending a cancellation signal.
+    /// Cancels the subscription by cancelling its token.
     #[frb]
     pub fn cancel(&self) {
-        if let Some(sender) = self.cancel_sender.lock().take() {
-            sender.send(()).unwrap();
-        }
+        self.cancel_token.cancel();
+    }
+}
+
+impl Drop for SubscriptionHandle {
+    // Disposing the handle from Dart ends the subscription, as dropping the
+    // oneshot sender used to.
+    fn drop(&mut self) {
+        self.cancel_token.cancel();
     }
 }
 
@@ -110,50 +273,695 @@ impl QuerySubscriber for CallbackSubscriberDartFn {
     }
 }
 
-/// Main Convex client struct, opaque to Dart, managing connections and operations.
-#[frb(opaque)]
-pub struct MobileConvexClient {
+/// Connection state shared by every handle attached to the same client.
+struct ClientCore {
     deployment_url: String, // URL of the Convex deployment
     client_id: String,     // Client ID for authentication
     client: OnceCell<ConvexClient>, // Lazy-initialized Convex client
-    rt: tokio::runtime::Runtime,    // Tokio runtime for async operations
+    rt: Handle,                     // Tokio runtime for async operations
+    owned_rt: Option<Runtime>,      // Runtime created by the client, if not supplied by the embedder
+    rate_limit_retries: AtomicU32,  // Automatic retries for rate-limited calls
+    engines: Mutex<HashMap<String, EngineAttachment>>, // Flutter engines attached to this client
+    cancel_token: CancellationToken, // Root of the cancellation tree for all handles
+    prefetched: Mutex<HashMap<QueryKey, PrefetchEntry>>, // Result cache filled by `prefetch`
+    prefetch_ttl_ms: AtomicU32,      // How long a prefetched query stays subscribed and cached
+    write_generation: Arc<AtomicU64>, // Bumped by every completed mutation
+    subscriptions: Mutex<HashMap<u64, ActiveSubscription>>, // Live subscriptions by id
+    next_subscription_id: AtomicU64, // Next id handed to a subscription
+    max_subscriptions: AtomicU32,    // Cap on live subscriptions, 0 for no limit
 }
 
-impl MobileConvexClient {
-    /// Creates a new MobileConvexClient instance with the given deployment URL and client ID.
-    #[frb]
-    pub fn new(deployment_url: String, client_id: String) -> MobileConvexClient {
+/// Key identifying a query by function name and its raw JSON arguments.
+type QueryKey = (String, BTreeMap<String, String>);
+
+/// Latest result of a prefetched query, with the write generation it was received in.
+type CacheSlot = Arc<Mutex<Option<(u64, String)>>>;
+
+/// A query kept warm by `prefetch`, with its latest result.
+struct PrefetchEntry {
+    value: CacheSlot,                // Latest result, once one has arrived
+    handle: Arc<SubscriptionHandle>, // Subscription keeping the query live
+}
+
+/// A live subscription tracked for the subscription cap.
+struct ActiveSubscription {
+    name: String,                    // Query function name
+    last_active: Instant,            // When it was created or last received an update
+    handle: Weak<SubscriptionHandle>, // Handle used to cancel it on eviction
+    prefetch: bool,                  // Whether it only keeps a prefetched query warm
+    owner: Arc<HandleState>,         // State of the handle that created it
+}
+
+type EvictionCallback = Arc<dyn Fn(u64, String) -> DartFnFuture<()> + Send + Sync>;
+
+/// State belonging to one `MobileConvexClient` handle rather than the shared
+/// core, so engines attached to the same client only see their own callbacks
+/// and traffic.
+struct HandleState {
+    rt: Handle,                                  // Runtime the callbacks are spawned on
+    on_evicted: Mutex<Option<EvictionCallback>>, // Notified when the cap evicts one of this handle's subscriptions
+    traffic: Mutex<TrafficStats>,                // Traffic through this handle, per function name and in total
+    data_budget: Mutex<Option<DataBudget>>,      // Budget on this handle's total traffic, if set
+    #[cfg(debug_assertions)]
+    call_tap: Mutex<Option<CallTap>>,            // Debug callback receiving this handle's calls
+}
+
+impl HandleState {
+    fn new(rt: Handle) -> HandleState {
+        HandleState {
+            rt,
+            on_evicted: Mutex::new(None),
+            traffic: Mutex::new(TrafficStats::default()),
+            data_budget: Mutex::new(None),
+            #[cfg(debug_assertions)]
+            call_tap: Mutex::new(None),
+        }
+    }
+
+    /// Drops the Dart callbacks installed through the handle, so none run
+    /// once it is closed or detached.
+    fn clear_callbacks(&self) {
+        *self.on_evicted.lock() = None;
+        *self.data_budget.lock() = None;
         #[cfg(debug_assertions)]
-        android_logger::init_once(Config::default().with_max_level(LevelFilter::Trace));
+        {
+            *self.call_tap.lock() = None;
+        }
+    }
+
+    /// Accounts a call of `size` bytes in the traffic stats and reports it to
+    /// the debug call tap, if one is set. `payload` is only rendered for the
+    /// tap, so release builds never pay for it.
+    fn record_call(
+        &self,
+        direction: CallDirection,
+        kind: CallKind,
+        function_name: &str,
+        size: u64,
+        payload: impl FnOnce() -> String,
+    ) {
+        self.account_traffic(direction, function_name, size);
+        #[cfg(debug_assertions)]
+        {
+            let tap = self.call_tap.lock().clone();
+            if let Some(tap) = tap {
+                let future = tap(TappedCall {
+                    direction,
+                    kind,
+                    function_name: function_name.to_string(),
+                    size,
+                    payload: truncate_payload(&payload()),
+                });
+                self.rt.spawn(async move {
+                    let _ = future.await;
+                });
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        let _ = (kind, payload);
+    }
+
+    /// Reports the outcome of a one-shot function call as an incoming call.
+    fn record_result(&self, kind: CallKind, function_name: &str, result: &Result<String, ClientError>) {
+        let direction = CallDirection::Incoming;
+        match result {
+            Ok(value) => {
+                let size = value.len() as u64;
+                self.record_call(direction, kind, function_name, size, || value.clone())
+            }
+            Err(error) => {
+                let size = display_len(error);
+                self.record_call(direction, kind, function_name, size, || error.to_string())
+            }
+        }
+    }
+
+    /// Adds a call to the per-function traffic stats and checks the data budget.
+    fn account_traffic(&self, direction: CallDirection, function_name: &str, size: u64) {
+        let total = {
+            let mut traffic = self.traffic.lock();
+            let stats = traffic
+                .functions
+                .entry(function_name.to_string())
+                .or_insert_with(|| FunctionTraffic {
+                    function_name: function_name.to_string(),
+                    ..Default::default()
+                });
+            match direction {
+                CallDirection::Outgoing => {
+                    stats.bytes_sent += size;
+                    stats.messages_sent += 1;
+                }
+                CallDirection::Incoming => {
+                    stats.bytes_received += size;
+                    stats.messages_received += 1;
+                }
+            }
+            traffic.total_bytes += size;
+            traffic.total_bytes
+        };
+        let mut data_budget = self.data_budget.lock();
+        if let Some(budget) = data_budget.as_mut() {
+            if !budget.notified && total > budget.max_bytes {
+                budget.notified = true;
+                let future = (budget.on_exceeded)(total);
+                self.rt.spawn(async move {
+                    let _ = future.await;
+                });
+            }
+        }
+    }
+}
+
+impl PrefetchEntry {
+    /// Whether the backing subscription is still delivering updates.
+    fn is_live(&self) -> bool {
+        !self.handle.cancel_token.is_cancelled()
+    }
+}
+
+/// How long a prefetched query stays subscribed and cached unless changed
+/// with `set_prefetch_ttl`.
+const DEFAULT_PREFETCH_TTL_MS: u32 = 60_000;
+
+/// Bookkeeping for one Flutter engine attached to a shared client.
+#[derive(Default)]
+struct EngineAttachment {
+    count: usize,                                // Live attachments from this engine
+    subscriptions: Vec<Weak<SubscriptionHandle>>, // Subscriptions created by this engine
+}
+
+impl ClientCore {
+    fn new(deployment_url: String, client_id: String) -> ClientCore {
+        if let Some(rt) = DEFAULT_RUNTIME.lock().clone() {
+            return Self::with_runtime(deployment_url, client_id, rt, None);
+        }
         let rt = tokio::runtime::Builder::new_multi_thread()
             .enable_all()
             .build()
             .unwrap();
-        MobileConvexClient {
+        Self::with_runtime(deployment_url, client_id, rt.handle().clone(), Some(rt))
+    }
+
+    fn with_runtime(
+        deployment_url: String,
+        client_id: String,
+        rt: Handle,
+        owned_rt: Option<Runtime>,
+    ) -> ClientCore {
+        #[cfg(debug_assertions)]
+        android_logger::init_once(Config::default().with_max_level(LevelFilter::Trace));
+        ClientCore {
             deployment_url,
             client_id,
             client: OnceCell::new(),
             rt,
+            owned_rt,
+            rate_limit_retries: AtomicU32::new(0),
+            engines: Mutex::new(HashMap::new()),
+            cancel_token: CancellationToken::new(),
+            prefetched: Mutex::new(HashMap::new()),
+            prefetch_ttl_ms: AtomicU32::new(DEFAULT_PREFETCH_TTL_MS),
+            write_generation: Arc::new(AtomicU64::new(0)),
+            subscriptions: Mutex::new(HashMap::new()),
+            next_subscription_id: AtomicU64::new(1),
+            max_subscriptions: AtomicU32::new(0),
+        }
+    }
+
+    /// Returns the cached result of a prefetched query, if it is still kept
+    /// warm and was received after the latest write.
+    fn cached_result(&self, name: &str, args: &HashMap<String, String>) -> Option<String> {
+        let key: QueryKey = (name.to_string(), args.clone().into_iter().collect());
+        let generation = self.write_generation.load(Ordering::Acquire);
+        self.prefetched
+            .lock()
+            .get(&key)
+            .filter(|entry| entry.is_live())
+            .and_then(|entry| match &*entry.value.lock() {
+                Some((received_in, value)) if *received_in == generation => Some(value.clone()),
+                _ => None,
+            })
+    }
+
+    /// Drops the cache entry backed by a subscription that has ended, however
+    /// it ended, so its last value is never served as current.
+    fn forget_prefetch(&self, id: u64) {
+        self.prefetched.lock().retain(|_, entry| entry.handle.id != id);
+    }
+
+    /// Starts a new write generation, so no prefetch result received before
+    /// the write is served again. The warm subscriptions stay live and refill
+    /// the cache with later updates.
+    fn invalidate_prefetched(&self) {
+        self.write_generation.fetch_add(1, Ordering::AcqRel);
+        for entry in self.prefetched.lock().values() {
+            *entry.value.lock() = None;
+        }
+    }
+
+    /// Stops keeping a prefetched query warm once its TTL has elapsed.
+    fn expire_prefetch(&self, key: &QueryKey, handle: &Arc<SubscriptionHandle>) {
+        let mut prefetched = self.prefetched.lock();
+        if prefetched
+            .get(key)
+            .is_some_and(|entry| Arc::ptr_eq(&entry.handle, handle))
+        {
+            prefetched.remove(key);
+            handle.cancel();
+        }
+    }
+
+    /// Tracks a new subscription, then enforces the subscription cap. A new
+    /// regular subscription is never the one evicted; a new prefetch may be.
+    fn register_subscription(
+        &self,
+        name: &str,
+        handle: &Arc<SubscriptionHandle>,
+        prefetch: bool,
+        owner: &Arc<HandleState>,
+    ) {
+        self.subscriptions.lock().insert(
+            handle.id,
+            ActiveSubscription {
+                name: name.to_string(),
+                last_active: Instant::now(),
+                handle: Arc::downgrade(handle),
+                prefetch,
+                owner: owner.clone(),
+            },
+        );
+        self.enforce_subscription_limit((!prefetch).then_some(handle.id));
+    }
+
+    /// Cancels subscriptions until no more than the cap are live. Prefetch
+    /// subscriptions go first, then the least recently updated ones; `protected`
+    /// is never evicted. Only evictions of regular subscriptions are reported,
+    /// to the `on_evicted` of the handle that created them, since prefetches
+    /// are not visible to the app.
+    fn enforce_subscription_limit(&self, protected: Option<u64>) {
+        let max = self.max_subscriptions.load(Ordering::Relaxed) as usize;
+        if max == 0 {
+            return;
+        }
+        let mut evicted = Vec::new();
+        {
+            let mut subscriptions = self.subscriptions.lock();
+            subscriptions.retain(|_, subscription| {
+                subscription
+                    .handle
+                    .upgrade()
+                    .is_some_and(|handle| !handle.cancel_token.is_cancelled())
+            });
+            while subscriptions.len() > max {
+                let Some(id) = subscriptions
+                    .iter()
+                    .filter(|(id, _)| Some(**id) != protected)
+                    .min_by_key(|(_, subscription)| (!subscription.prefetch, subscription.last_active))
+                    .map(|(id, _)| *id)
+                else {
+                    break;
+                };
+                if let Some(subscription) = subscriptions.remove(&id) {
+                    evicted.push((id, subscription));
+                }
+            }
+        }
+        for (id, subscription) in evicted {
+            debug!("Evicting subscription {id} ({})", subscription.name);
+            if let Some(handle) = subscription.handle.upgrade() {
+                handle.cancel();
+            }
+            if subscription.prefetch {
+                continue;
+            }
+            let on_evicted = subscription.owner.on_evicted.lock().clone();
+            if let Some(on_evicted) = on_evicted {
+                let future = on_evicted(id, subscription.name);
+                self.rt.spawn(async move {
+                    let _ = future.await;
+                });
+            }
+        }
+    }
+
+    /// Marks a subscription as recently active, protecting it from eviction.
+    fn touch_subscription(&self, id: u64) {
+        if let Some(subscription) = self.subscriptions.lock().get_mut(&id) {
+            subscription.last_active = Instant::now();
+        }
+    }
+
+    /// Stops tracking a subscription whose loop has ended.
+    fn unregister_subscription(&self, id: u64) {
+        self.subscriptions.lock().remove(&id);
+    }
+}
+
+impl Drop for ClientCore {
+    fn drop(&mut self) {
+        self.cancel_token.cancel();
+        // Dropping a runtime blocks on its workers, which panics when the last
+        // handle goes away on a runtime thread; shut it down without waiting instead.
+        if let Some(rt) = self.owned_rt.take() {
+            rt.shutdown_background();
+        }
+    }
+}
+
+/// Key identifying a shared client by deployment URL and client ID.
+type ClientKey = (String, String);
+
+// Runtime set with `set_default_runtime`, used by `new` and `attach` instead of
+// starting a runtime per client.
+static DEFAULT_RUNTIME: Lazy<Mutex<Option<Handle>>> = Lazy::new(Default::default);
+
+/// Makes clients created afterwards through `new` or `attach`, including those
+/// created from Dart, run on `rt` instead of starting their own thread pool.
+/// Intended for Rust code that links this crate and already hosts a Tokio
+/// runtime in the process; call it before the first client is created. The
+/// runtime must have IO and time enabled and must outlive those clients.
+#[frb(ignore)]
+pub fn set_default_runtime(rt: Handle) {
+    *DEFAULT_RUNTIME.lock() = Some(rt);
+}
+
+// Clients shared between Flutter engines, keyed by deployment URL and client ID.
+// An entry stays here, keeping its connection alive, while any engine is attached.
+static SHARED_CLIENTS: Lazy<Mutex<HashMap<ClientKey, Arc<ClientCore>>>> = Lazy::new(Default::default);
+
+/// Main Convex client struct, opaque to Dart, managing connections and operations.
+#[frb(opaque)]
+pub struct MobileConvexClient {
+    core: Arc<ClientCore>,      // Shared connection state
+    state: Arc<HandleState>,    // State of this handle alone
+    engine_id: Option<String>, // Engine this handle is attached for, if shared
+    detached: AtomicBool,      // Whether `detach` already ran for this handle
+    cancel_token: CancellationToken, // Cancels all work started through this handle
+}
+
+impl MobileConvexClient {
+    /// Creates a new MobileConvexClient instance with the given deployment URL and client ID.
+    #[frb]
+    pub fn new(deployment_url: String, client_id: String) -> MobileConvexClient {
+        MobileConvexClient::with_core(Arc::new(ClientCore::new(deployment_url, client_id)), None)
+    }
+
+    /// Creates a client that runs on an existing Tokio runtime instead of
+    /// starting its own thread pool. Intended for Rust code that links this
+    /// crate and already hosts a runtime in the process; the runtime must have
+    /// IO and time enabled and must outlive the client. To have clients created
+    /// from Dart share the runtime too, use `set_default_runtime`.
+    #[frb(ignore)]
+    pub fn new_with_runtime(
+        deployment_url: String,
+        client_id: String,
+        rt: Handle,
+    ) -> MobileConvexClient {
+        MobileConvexClient::with_core(
+            Arc::new(ClientCore::with_runtime(deployment_url, client_id, rt, None)),
+            None,
+        )
+    }
+
+    /// Attaches a Flutter engine to the client shared by all engines using the
+    /// same deployment URL and client ID, creating it on first use.
+    ///
+    /// Each engine gets its own handle, so subscription callbacks are delivered
+    /// to the engine that subscribed. The connection stays open until every
+    /// engine has detached.
+    #[frb]
+    pub fn attach(
+        deployment_url: String,
+        client_id: String,
+        engine_id: String,
+    ) -> MobileConvexClient {
+        let mut shared = SHARED_CLIENTS.lock();
+        let core = shared
+            .entry((deployment_url.clone(), client_id.clone()))
+            .or_insert_with(|| Arc::new(ClientCore::new(deployment_url, client_id)))
+            .clone();
+        core.engines
+            .lock()
+            .entry(engine_id.clone())
+            .or_default()
+            .count += 1;
+        drop(shared);
+        debug!("Engine {engine_id} attached");
+        MobileConvexClient::with_core(core, Some(engine_id))
+    }
+
+    fn with_core(core: Arc<ClientCore>, engine_id: Option<String>) -> MobileConvexClient {
+        let cancel_token = core.cancel_token.child_token();
+        let state = Arc::new(HandleState::new(core.rt.clone()));
+        MobileConvexClient {
+            core,
+            state,
+            engine_id,
+            detached: AtomicBool::new(false),
+            cancel_token,
+        }
+    }
+
+    /// Closes this client: every in-flight query, mutation and action started
+    /// through it fails with `Cancelled`, and its subscriptions stop after
+    /// reporting `Cancelled` through `on_error`. Shared handles are also detached.
+    /// Runs automatically when the handle is disposed.
+    #[frb]
+    pub fn close(&self) {
+        self.cancel_token.cancel();
+        self.state.clear_callbacks();
+        self.detach();
+    }
+
+    /// Resolves to `Cancelled` as soon as this client is closed, otherwise to `fut`'s result.
+    async fn cancellable<T>(
+        &self,
+        fut: impl Future<Output = Result<T, ClientError>>,
+    ) -> Result<T, ClientError> {
+        tokio::select! {
+            biased;
+            _ = self.cancel_token.cancelled() => Err(ClientError::Cancelled),
+            result = fut => result,
+        }
+    }
+
+    /// Spawns `fut` on the client runtime, aborting it when this client is closed.
+    async fn spawn_cancellable<T: Send + 'static>(
+        &self,
+        fut: impl Future<Output = anyhow::Result<T>> + Send + 'static,
+    ) -> anyhow::Result<T> {
+        let token = self.cancel_token.clone();
+        self.core
+            .rt
+            .spawn(async move {
+                tokio::select! {
+                    biased;
+                    _ = token.cancelled() => Err(anyhow::anyhow!("Cancelled")),
+                    result = fut => result,
+                }
+            })
+            .await?
+    }
+
+    /// Releases this engine's attachment and drops the callbacks installed
+    /// through this handle. When an engine's last attachment is released its
+    /// subscriptions are cancelled; when no engine remains the shared client
+    /// is released. Runs automatically when the handle is dropped.
+    #[frb]
+    pub fn detach(&self) {
+        let Some(engine_id) = &self.engine_id else {
+            return;
+        };
+        if self.detached.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        self.state.clear_callbacks();
+        // Lock order: SHARED_CLIENTS before the core's engines.
+        let mut shared = SHARED_CLIENTS.lock();
+        let mut engines = self.core.engines.lock();
+        if let Some(attachment) = engines.get_mut(engine_id) {
+            attachment.count -= 1;
+            if attachment.count == 0 {
+                for handle in attachment.subscriptions.drain(..) {
+                    if let Some(handle) = handle.upgrade() {
+                        handle.cancel();
+                    }
+                }
+                engines.remove(engine_id);
+                debug!("Engine {engine_id} detached");
+            }
+        }
+        if engines.is_empty() {
+            let key = (self.core.deployment_url.clone(), self.core.client_id.clone());
+            if shared.get(&key).is_some_and(|core| Arc::ptr_eq(core, &self.core)) {
+                shared.remove(&key);
+            }
+        }
+    }
+
+    /// Records a subscription against the engine this handle is attached for.
+    fn track_subscription(&self, handle: &Arc<SubscriptionHandle>) {
+        if let Some(engine_id) = &self.engine_id {
+            if let Some(attachment) = self.core.engines.lock().get_mut(engine_id) {
+                attachment.subscriptions.retain(|handle| handle.strong_count() > 0);
+                attachment.subscriptions.push(Arc::downgrade(handle));
+            }
+        }
+    }
+
+    /// Installs a callback receiving every call made through this handle:
+    /// function calls and their results, subscription updates and auth
+    /// changes, for diagnosing missed or unexpected updates.
+    ///
+    /// This is a call-level tap, observed where this client meets the Convex
+    /// client; the websocket protocol messages are not exposed, so e.g. a
+    /// transition that changed no subscribed value does not show up. Other
+    /// engines' calls are not reported, and the callback is dropped when the
+    /// handle is closed or detached. Only active in debug builds; a no-op in
+    /// release builds.
+    #[frb]
+    pub fn set_call_tap(
+        &self,
+        on_call: impl Fn(TappedCall) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) {
+        #[cfg(debug_assertions)]
+        {
+            *self.state.call_tap.lock() = Some(Arc::new(on_call));
+        }
+        #[cfg(not(debug_assertions))]
+        {
+            let _ = on_call;
+            debug!("Call tap is only available in debug builds");
+        }
+    }
+
+    /// Removes the callback installed with `set_call_tap`.
+    #[frb]
+    pub fn clear_call_tap(&self) {
+        #[cfg(debug_assertions)]
+        {
+            *self.state.call_tap.lock() = None;
+        }
+    }
+
+    /// Returns the traffic exchanged with the backend through this handle so
+    /// far, per function name. Engines sharing a client each see only their own.
+    #[frb]
+    pub fn traffic_stats(&self) -> Vec<FunctionTraffic> {
+        let mut stats: Vec<FunctionTraffic> = self.state.traffic.lock().functions.values().cloned().collect();
+        stats.sort_by(|a, b| a.function_name.cmp(&b.function_name));
+        stats
+    }
+
+    /// Clears this handle's traffic stats and re-arms its data budget callback.
+    #[frb]
+    pub fn reset_traffic_stats(&self) {
+        *self.state.traffic.lock() = TrafficStats::default();
+        if let Some(budget) = self.state.data_budget.lock().as_mut() {
+            budget.notified = false;
+        }
+    }
+
+    /// Sets a budget on the total bytes sent and received through this handle.
+    /// `on_exceeded` is called once, with the total so far, when the budget is
+    /// first exceeded, so the app can throttle heavy subscriptions; it is
+    /// re-armed by `reset_traffic_stats` or a new budget, and dropped when the
+    /// handle is closed or detached.
+    #[frb]
+    pub fn set_data_budget(
+        &self,
+        max_bytes: u64,
+        on_exceeded: impl Fn(u64) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) {
+        *self.state.data_budget.lock() = Some(DataBudget {
+            max_bytes,
+            on_exceeded: Arc::new(on_exceeded),
+            notified: false,
+        });
+    }
+
+    /// Removes the budget set with `set_data_budget`.
+    #[frb]
+    pub fn clear_data_budget(&self) {
+        *self.state.data_budget.lock() = None;
+    }
+
+    /// Caps the number of simultaneously live subscriptions on this client,
+    /// evicting right away if more are live. When the cap is exceeded,
+    /// prefetched queries are dropped first, then the least recently updated
+    /// subscriptions, which are cancelled and reported with their id and query
+    /// name. A prefetch never evicts a regular subscription. Pass 0 to remove the cap.
+    ///
+    /// The cap covers the whole connection, shared by every attached engine,
+    /// but `on_evicted` only hears about subscriptions created through this handle.
+    #[frb]
+    pub fn set_max_subscriptions(
+        &self,
+        max: u32,
+        on_evicted: impl Fn(u64, String) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) {
+        *self.state.on_evicted.lock() = Some(Arc::new(on_evicted));
+        self.core.max_subscriptions.store(max, Ordering::Relaxed);
+        self.core.enforce_subscription_limit(None);
+    }
+
+    /// Sets how long queries passed to `prefetch` afterwards stay subscribed
+    /// and cached, in milliseconds. Defaults to 60 seconds.
+    #[frb]
+    pub fn set_prefetch_ttl(&self, ttl_ms: u32) {
+        self.core.prefetch_ttl_ms.store(ttl_ms, Ordering::Relaxed);
+    }
+
+    /// Sets how many times a rate-limited query, mutation or action is retried
+    /// after the server-indicated delay before `RateLimited` is returned. Defaults to 0.
+    #[frb]
+    pub fn set_rate_limit_retries(&self, retries: u32) {
+        self.core.rate_limit_retries.store(retries, Ordering::Relaxed);
+    }
+
+    /// Runs `call`, retrying it while it fails with `RateLimited` and retries remain.
+    async fn with_rate_limit_retry<F, Fut>(&self, mut call: F) -> Result<String, ClientError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<String, ClientError>>,
+    {
+        let mut attempts = 0;
+        loop {
+            match call().await {
+                Err(ClientError::RateLimited { retry_after_ms, .. })
+                    if attempts < self.core.rate_limit_retries.load(Ordering::Relaxed) =>
+                {
+                    attempts += 1;
+                    let delay = retry_after_ms.unwrap_or(DEFAULT_RETRY_AFTER_MS);
+                    debug!("Rate limited, retrying in {delay}ms (attempt {attempts})");
+                    self.spawn_cancellable(async move {
+                        tokio::time::sleep(Duration::from_millis(delay)).await;
+                        Ok(())
+                    })
+                    .await?;
+                }
+                result => return result,
+            }
         }
     }
 
     /// Retrieves or initializes a connected Convex client.
     async fn connected_client(&self) -> anyhow::Result<ConvexClient> {
-        let url = self.deployment_url.clone();
-        self.client
+        let url = self.core.deployment_url.clone();
+        self.core.client
             .get_or_try_init(async {
-                let client_id = self.client_id.to_owned();
-                self.rt
-                    .spawn(async move {
-                        ConvexClientBuilder::new(url.as_str())
-                            .with_client_id(&client_id)
-                            .build()
-                            .await
-                    })
-                    .await?
+                let client_id = self.core.client_id.to_owned();
+                self.spawn_cancellable(async move {
+                    ConvexClientBuilder::new(url.as_str())
+                        .with_client_id(&client_id)
+                        .build()
+                        .await
+                })
+                .await
             })
             .await
-            .map(|client_ref| client_ref.clone())
+            .cloned()
     }
 
     /// Executes a query on the Convex backend.
@@ -163,11 +971,89 @@ impl MobileConvexClient {
         name: String,
         args: HashMap<String, String>,
     ) -> Result<String, ClientError> {
-        let mut client = self.connected_client().await?;
-        debug!("got the client");
-        let result = client.query(name.as_str(), parse_json_args(args)).await?;
-        debug!("got the result");
-        handle_direct_function_result(result)
+        if let Some(value) = self.core.cached_result(&name, &args) {
+            debug!("Serving {name} from the prefetch cache");
+            return Ok(value);
+        }
+        self.cancellable(self.with_rate_limit_retry(|| {
+            let (name, args) = (name.clone(), args.clone());
+            async move {
+                let mut client = self.connected_client().await?;
+                debug!("got the client");
+                self.state.record_call(
+                    CallDirection::Outgoing,
+                    CallKind::QueryCalled,
+                    &name,
+                    args_payload_len(&args),
+                    || args_payload(&args),
+                );
+                let result = client.query(name.as_str(), parse_json_args(args)).await?;
+                debug!("got the result");
+                let result = handle_direct_function_result(result);
+                self.state.record_result(CallKind::QueryReturned, &name, &result);
+                result
+            }
+        }))
+        .await
+    }
+
+    /// Warms up queries the app is likely to need next, e.g. detail pages of
+    /// visible list items, so a later `query` for them resolves from the cache.
+    ///
+    /// Queries are subscribed one at a time, yielding to other tasks between
+    /// them, and are kept live for the TTL set with `set_prefetch_ttl`, 60
+    /// seconds by default. This only paces the warm-up; there is no scheduler
+    /// priority, so once sent each warm-up is an ordinary subscription. Returns
+    /// once every query is subscribed; results fill the cache as they arrive.
+    #[frb]
+    pub async fn prefetch(
+        &self,
+        queries: Vec<(String, HashMap<String, String>)>,
+    ) -> Result<(), ClientError> {
+        self.cancellable(async {
+            for (name, args) in queries {
+                let key: QueryKey = (name.clone(), args.clone().into_iter().collect());
+                if self
+                    .core
+                    .prefetched
+                    .lock()
+                    .get(&key)
+                    .is_some_and(PrefetchEntry::is_live)
+                {
+                    continue;
+                }
+                let value: CacheSlot = Arc::new(Mutex::new(None));
+                let subscriber = Arc::new(CacheSubscriber::new(
+                    value.clone(),
+                    self.core.write_generation.clone(),
+                ));
+                let handle = self
+                    .internal_subscribe(name, args, subscriber, None, true)
+                    .await?;
+                if handle.cancel_token.is_cancelled() {
+                    debug!("Subscription cap reached, skipping remaining prefetches");
+                    break;
+                }
+                self.core.prefetched.lock().insert(
+                    key.clone(),
+                    PrefetchEntry {
+                        value,
+                        handle: handle.clone(),
+                    },
+                );
+                let core = Arc::downgrade(&self.core);
+                let ttl = Duration::from_millis(self.core.prefetch_ttl_ms.load(Ordering::Relaxed).into());
+                self.core.rt.spawn(async move {
+                    tokio::time::sleep(ttl).await;
+                    if let Some(core) = core.upgrade() {
+                        core.expire_prefetch(&key, &handle);
+                    }
+                });
+                tokio::task::yield_now().await;
+            }
+            Ok::<_, ClientError>(())
+        })
+        .await
     }
 
     /// Subscribes to real-time updates from a Convex query.
@@ -183,7 +1069,50 @@ impl MobileConvexClient {
             on_update: Box::new(on_update),
             on_error: Box::new(on_error),
         });
-        self.internal_subscribe(name, args, subscriber).await.map_err(Into::into)
+        self.cancellable(async {
+            self.internal_subscribe(name, args, subscriber, None, false)
+                .await
+                .map_err(ClientError::from)
+        })
+        .await
+    }
+
+    /// Subscribes to a Convex query, reshaping each value on the Rust side with
+    /// the given transforms before it reaches Dart.
+    ///
+    /// Fails with `InternalError` when the crate is built without the
+    /// `transforms` feature.
+    #[frb]
+    pub async fn subscribe_with_transform(
+        &self,
+        name: String,
+        args: HashMap<String, String>,
+        transforms: Vec<SubscriptionTransform>,
+        on_update: impl Fn(String) -> DartFnFuture<()> + Send + Sync + 'static,
+        on_error: impl Fn(String, Option<String>) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<Arc<SubscriptionHandle>, ClientError> {
+        #[cfg(not(feature = "transforms"))]
+        {
+            let _ = (name, args, transforms, on_update, on_error);
+            Err(ClientError::InternalError {
+                msg: "subscribe_with_transform requires the `transforms` feature".to_string(),
+            })
+        }
+        #[cfg(feature = "transforms")]
+        {
+            let subscriber = Arc::new(CallbackSubscriberDartFn {
+                on_update: Box::new(on_update),
+                on_error: Box::new(on_error),
+            });
+            let mapper: ValueMapper =
+                Arc::new(move |value| apply_transforms(&transforms, value));
+            self.cancellable(async {
+                self.internal_subscribe(name, args, subscriber, Some(mapper), false)
+                    .await
+                    .map_err(ClientError::from)
+            })
+            .await
+        }
     }
 
     /// Internal method for subscription logic.
@@ -192,36 +1121,67 @@ impl MobileConvexClient {
         name: String,
         args: HashMap<String, String>,
         subscriber: Arc<dyn QuerySubscriber>,
+        mapper: Option<ValueMapper>,
+        prefetch: bool,
     ) -> anyhow::Result<Arc<SubscriptionHandle>> {
         let mut client = self.connected_client().await?;
         debug!("New subscription");
+        self.state.record_call(
+            CallDirection::Outgoing,
+            CallKind::Subscribed,
+            &name,
+            args_payload_len(&args),
+            || args_payload(&args),
+        );
         let mut subscription = client
             .subscribe(name.as_str(), parse_json_args(args))
             .await?;
-        let (cancel_sender, cancel_receiver) = oneshot::channel::<()>();
-        self.rt.spawn(async move {
-            let cancel_fut = cancel_receiver.fuse();
+        let client_token = self.cancel_token.clone();
+        let cancel_token = client_token.child_token();
+        let loop_token = cancel_token.clone();
+        let id = self.core.next_subscription_id.fetch_add(1, Ordering::Relaxed);
+        let handle = Arc::new(SubscriptionHandle::new(id, cancel_token));
+        self.core.register_subscription(&name, &handle, prefetch, &self.state);
+        let core = self.core.clone();
+        let state = self.state.clone();
+        self.core.rt.spawn(async move {
+            let cancel_fut = loop_token.cancelled().fuse();
             pin_mut!(cancel_fut);
             loop {
                 select_biased! {
                     new_val = subscription.next().fuse() => {
                         let new_val = new_val.expect("Client dropped prematurely");
+                        core.touch_subscription(id);
                         match new_val {
                             FunctionResult::Value(value) => {
                                 debug!("Updating with {value:?}");
-                                subscriber.on_update(serde_json::to_string(
-                                    &serde_json::Value::from(value),
-                                ).unwrap());
+                                let json = serde_json::Value::from(value);
+                                let raw = serde_json::to_string(&json).unwrap();
+                                state.record_call(CallDirection::Incoming, CallKind::SubscriptionUpdated, &name, raw.len() as u64, || raw.clone());
+                                let update = match &mapper {
+                                    None => raw,
+                                    Some(mapper) => match mapper(json) {
+                                        Ok(Some(json)) => serde_json::to_string(&json).unwrap(),
+                                        Ok(None) => continue,
+                                        Err(message) => {
+                                            subscriber.on_error(message, None);
+                                            continue;
+                                        }
+                                    },
+                                };
+                                subscriber.on_update(update);
                             }
                             FunctionResult::ErrorMessage(message) => {
+                                state.record_call(CallDirection::Incoming, CallKind::SubscriptionFailed, &name, message.len() as u64, || message.clone());
                                 subscriber.on_error(message, None);
                             }
-                            FunctionResult::ConvexError(error) => subscriber.on_error(
-                                error.message,
-                                Some(serde_json::ser::to_string(
+                            FunctionResult::ConvexError(error) => {
+                                let data = serde_json::ser::to_string(
                                     &serde_json::Value::from(error.data),
-                                ).unwrap()),
-                            ),
+                                ).unwrap();
+                                state.record_call(CallDirection::Incoming, CallKind::SubscriptionFailed, &name, data.len() as u64, || data.clone());
+                                subscriber.on_error(error.message, Some(data));
+                            }
                         }
                     }
                     _ = cancel_fut => {
@@ -229,20 +1189,48 @@ impl MobileConvexClient {
                     }
                 }
             }
+            core.unregister_subscription(id);
+            core.forget_prefetch(id);
+            if client_token.is_cancelled() {
+                subscriber.on_error(ClientError::Cancelled.to_string(), None);
+            }
             debug!("Subscription canceled");
         });
-        Ok(Arc::new(SubscriptionHandle::new(cancel_sender)))
+        self.track_subscription(&handle);
+        Ok(handle)
     }
 
     /// Executes a mutation on the Convex backend.
+    ///
+    /// The client resolves a mutation once its write is reflected in its own
+    /// query results, so later reads through this client, from any engine
+    /// attached to it, observe the write.
     #[frb]
     pub async fn mutation(
         &self,
         name: String,
         args: HashMap<String, String>,
     ) -> Result<String, ClientError> {
-        let result = self.internal_mutation(name, args).await?;
-        handle_direct_function_result(result)
+        let value = self
+            .cancellable(self.with_rate_limit_retry(|| {
+                let (name, args) = (name.clone(), args.clone());
+                async move {
+                    self.state.record_call(
+                        CallDirection::Outgoing,
+                        CallKind::MutationCalled,
+                        &name,
+                        args_payload_len(&args),
+                        || args_payload(&args),
+                    );
+                    let result = self.internal_mutation(name.clone(), args).await?;
+                    let result = handle_direct_function_result(result);
+                    self.state.record_result(CallKind::MutationReturned, &name, &result);
+                    result
+                }
+            }))
+            .await?;
+        self.core.invalidate_prefetched();
+        Ok(value)
     }
 
     /// Internal method for mutation logic.
@@ -252,9 +1240,8 @@ impl MobileConvexClient {
         args: HashMap<String, String>,
     ) -> anyhow::Result<FunctionResult> {
         let mut client = self.connected_client().await?;
-        self.rt
-            .spawn(async move { client.mutation(&name, parse_json_args(args)).await })
-            .await?
+        self.spawn_cancellable(async move { client.mutation(&name, parse_json_args(args)).await })
+            .await
     }
 
     /// Executes an action on the Convex backend.
@@ -265,9 +1252,24 @@ impl MobileConvexClient {
         args: HashMap<String, String>,
     ) -> Result<String, ClientError> {
         debug!("Running action: {}", name);
-        let result = self.internal_action(name, args).await?;
-        debug!("Got action result: {:?}", result);
-        handle_direct_function_result(result)
+        self.cancellable(self.with_rate_limit_retry(|| {
+            let (name, args) = (name.clone(), args.clone());
+            async move {
+                self.state.record_call(
+                    CallDirection::Outgoing,
+                    CallKind::ActionCalled,
+                    &name,
+                    args_payload_len(&args),
+                    || args_payload(&args),
+                );
+                let result = self.internal_action(name.clone(), args).await?;
+                debug!("Got action result: {:?}", result);
+                let result = handle_direct_function_result(result);
+                self.state.record_result(CallKind::ActionReturned, &name, &result);
+                result
+            }
+        }))
+        .await
     }
 
     /// Internal method for action logic.
@@ -278,25 +1280,101 @@ impl MobileConvexClient {
     ) -> anyhow::Result<FunctionResult> {
         let mut client = self.connected_client().await?;
         debug!("Running action: {}", name);
-        self.rt
-            .spawn(async move { client.action(&name, parse_json_args(args)).await })
-            .await?
+        self.spawn_cancellable(async move { client.action(&name, parse_json_args(args)).await })
+            .await
     }
 
     /// Sets authentication token for the client.
     #[frb]
     pub async fn set_auth(&self, token: Option<String>) -> Result<(), ClientError> {
-        Ok(self.internal_set_auth(token).await?)
+        self.cancellable(async { self.internal_set_auth(token).await.map_err(ClientError::from) })
+            .await
     }
 
     /// Internal method for setting authentication.
     async fn internal_set_auth(&self, token: Option<String>) -> anyhow::Result<()> {
         let mut client = self.connected_client().await?;
-        self.rt
-            .spawn(async move { client.set_auth(token).await })
-            .await
-            .map_err(|e| e.into())
+        // Never hand the token itself to the tap.
+        let size = token.as_ref().map_or(4, |token| token.len() + 2) as u64;
+        let redacted = token.is_some();
+        self.state.record_call(CallDirection::Outgoing, CallKind::AuthSet, "", size, || {
+            if redacted { "\"[redacted]\"" } else { "null" }.to_string()
+        });
+        self.spawn_cancellable(async move {
+            client.set_auth(token).await;
+            Ok(())
+        })
+        .await
+    }
+}
+
+impl Drop for MobileConvexClient {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+/// Utility function to render FFI arguments, whose values are already JSON, as a JSON object.
+fn args_payload(raw_args: &HashMap<String, String>) -> String {
+    let fields: Vec<String> = raw_args
+        .iter()
+        .map(|(k, v)| format!("{}:{}", serde_json::Value::from(k.as_str()), v))
+        .collect();
+    format!("{{{}}}", fields.join(","))
+}
+
+/// Utility function to compute the length of `args_payload` without rendering it.
+fn args_payload_len(raw_args: &HashMap<String, String>) -> u64 {
+    let fields: usize = raw_args
+        .iter()
+        .map(|(k, v)| json_string_len(k) + 1 + v.len())
+        .sum();
+    (2 + fields + raw_args.len().saturating_sub(1)) as u64
+}
+
+/// Utility function to compute the length of `s` encoded as a JSON string by serde_json.
+fn json_string_len(s: &str) -> usize {
+    2 + s
+        .chars()
+        .map(|c| match c {
+            '"' | '\\' | '\n' | '\r' | '\t' | '\u{8}' | '\u{c}' => 2,
+            c if (c as u32) < 0x20 => 6,
+            c => c.len_utf8(),
+        })
+        .sum::<usize>()
+}
+
+/// Utility function to compute the length of a value's `Display` output without allocating.
+fn display_len(value: &impl std::fmt::Display) -> u64 {
+    struct Counter(usize);
+    impl std::fmt::Write for Counter {
+        fn write_str(&mut self, s: &str) -> std::fmt::Result {
+            self.0 += s.len();
+            Ok(())
+        }
+    }
+    let mut counter = Counter(0);
+    let _ = std::fmt::write(&mut counter, format_args!("{value}"));
+    counter.0 as u64
+}
+
+/// Longest payload, in bytes, forwarded to the debug call tap.
+#[cfg(debug_assertions)]
+const MAX_TAPPED_PAYLOAD: usize = 1024;
+
+/// Utility function to cut a payload down to at most `MAX_TAPPED_PAYLOAD` bytes,
+/// on a char boundary, marking the cut with `…`.
+#[cfg(debug_assertions)]
+fn truncate_payload(payload: &str) -> String {
+    const ELLIPSIS: &str = "…";
+    if payload.len() <= MAX_TAPPED_PAYLOAD {
+        return payload.to_string();
+    }
+    let mut end = MAX_TAPPED_PAYLOAD - ELLIPSIS.len();
+    while !payload.is_char_boundary(end) {
+        end -= 1;
     }
+    format!("{}{ELLIPSIS}", &payload[..end])
 }
 
 /// Utility function to parse HashMap arguments into Convex Value format.
@@ -324,6 +1402,355 @@ fn handle_direct_function_result(result: FunctionResult) -> Result<String, Clien
         FunctionResult::ConvexError(e) => Err(ClientError::ConvexError {
             data: serde_json::ser::to_string(&serde_json::Value::from(e.data)).unwrap(),
         }),
-        FunctionResult::ErrorMessage(msg) => Err(ClientError::ServerError { msg }),
+        FunctionResult::ErrorMessage(msg) => Err(match parse_rate_limit(&msg) {
+            Some(retry_after_ms) => ClientError::RateLimited { msg, retry_after_ms },
+            None => ClientError::ServerError { msg },
+        }),
+    }
+}
+
+/// Delay used for automatic retries when the server did not say how long to wait.
+const DEFAULT_RETRY_AFTER_MS: u64 = 1000;
+
+/// Error codes the backend reports when it throttles or sheds a request.
+const RATE_LIMIT_CODES: [&str; 4] = [
+    "RateLimited",
+    "Overloaded",
+    "TooManyConcurrentRequests",
+    "TooManyRequests",
+];
+
+/// Recognizes throttling/overload errors reported by the backend itself.
+/// Returns `None` for other errors, otherwise the retry-after delay if one is given.
+///
+/// Only the backend's error codes, as whole words, count; errors thrown by
+/// function code ("Uncaught ...") are never treated as throttling, whatever
+/// their text, so they are not retried.
+fn parse_rate_limit(msg: &str) -> Option<Option<u64>> {
+    if msg.contains("Uncaught") {
+        return None;
+    }
+    let is_throttled = msg
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .any(|word| RATE_LIMIT_CODES.contains(&word));
+    if !is_throttled {
+        return None;
+    }
+    Some(parse_retry_after(msg))
+}
+
+/// Extracts a "retry after N [unit]" delay in milliseconds from an error message.
+/// A bare number follows the HTTP Retry-After convention of seconds; an
+/// unrecognized unit yields `None` rather than a guess.
+fn parse_retry_after(msg: &str) -> Option<u64> {
+    let lower = msg.to_ascii_lowercase();
+    let rest = ["retry-after", "retry after", "retryafter"]
+        .iter()
+        .find_map(|key| lower.find(key).map(|i| &lower[i + key.len()..]))?;
+    let rest = rest.trim_start_matches([':', '=', ' ']);
+    let digits_len = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+    let amount: u64 = rest[..digits_len].parse().ok()?;
+    let unit: String = rest[digits_len..]
+        .trim_start()
+        .chars()
+        .take_while(|c| c.is_ascii_alphabetic())
+        .collect();
+    let millis_per_unit = match unit.as_str() {
+        "ms" | "msec" | "msecs" | "millis" | "millisecond" | "milliseconds" => 1,
+        "" | "s" | "sec" | "secs" | "second" | "seconds" => 1_000,
+        "m" | "min" | "mins" | "minute" | "minutes" => 60_000,
+        _ => return None,
+    };
+    Some(amount.saturating_mul(millis_per_unit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn args_payload_len_matches_rendering() {
+        let args = maplit::hashmap! {
+            "plain".to_string() => "1".to_string(),
+            "quo\"te\\".to_string() => "\"x\"".to_string(),
+            "ctl\n\u{1}\u{8}".to_string() => "[1,2]".to_string(),
+            "ünï".to_string() => "null".to_string(),
+        };
+        let rendered = args_payload(&args);
+        assert!(serde_json::from_str::<serde_json::Value>(&rendered).is_ok());
+        assert_eq!(args_payload_len(&args), rendered.len() as u64);
+        assert_eq!(args_payload_len(&HashMap::new()), 2);
+    }
+
+    #[test]
+    fn display_len_counts_bytes() {
+        let error = ClientError::ServerError { msg: "bäd".to_string() };
+        assert_eq!(display_len(&error), error.to_string().len() as u64);
     }
-}
\ No newline at end of file
+
+    #[cfg(debug_assertions)]
+    #[test]
+    fn truncated_payloads_fit_the_limit() {
+        let short = "a".repeat(MAX_TAPPED_PAYLOAD);
+        assert_eq!(truncate_payload(&short), short);
+        for filler in ["a", "é", "😀"] {
+            let long = filler.repeat(MAX_TAPPED_PAYLOAD + 1);
+            let cut = truncate_payload(&long);
+            assert!(cut.len() <= MAX_TAPPED_PAYLOAD);
+            assert!(cut.ends_with('…'));
+        }
+    }
+
+    #[tokio::test]
+    async fn clients_run_on_the_default_runtime() {
+        set_default_runtime(Handle::current());
+        let client = MobileConvexClient::new("url".into(), "runtime".into());
+        let attached = MobileConvexClient::attach("url".into(), "runtime".into(), "engine".into());
+        *DEFAULT_RUNTIME.lock() = None;
+        for core in [&client.core, &attached.core] {
+            assert!(core.owned_rt.is_none());
+            assert_eq!(core.rt.runtime_flavor(), tokio::runtime::RuntimeFlavor::CurrentThread);
+        }
+    }
+
+    #[test]
+    fn dropping_a_subscription_handle_cancels_it() {
+        let token = CancellationToken::new();
+        drop(SubscriptionHandle::new(1, token.clone()));
+        assert!(token.is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn ended_prefetch_subscriptions_are_not_served() {
+        let client = MobileConvexClient::new_with_runtime("url".into(), "id".into(), Handle::current());
+        let args = maplit::hashmap! { "id".to_string() => "1".to_string() };
+        let key: QueryKey = ("items:get".to_string(), args.clone().into_iter().collect());
+        let handle = Arc::new(SubscriptionHandle::new(7, CancellationToken::new()));
+        client.core.prefetched.lock().insert(
+            key,
+            PrefetchEntry {
+                value: Arc::new(Mutex::new(Some((0, "42".to_string())))),
+                handle: handle.clone(),
+            },
+        );
+        assert_eq!(client.core.cached_result("items:get", &args), Some("42".to_string()));
+        handle.cancel();
+        assert_eq!(client.core.cached_result("items:get", &args), None);
+        client.core.forget_prefetch(7);
+        assert!(client.core.prefetched.lock().is_empty());
+    }
+
+    #[tokio::test]
+    async fn prefetch_values_received_before_a_write_are_not_served() {
+        let client = MobileConvexClient::new_with_runtime("url".into(), "id".into(), Handle::current());
+        let args = maplit::hashmap! { "id".to_string() => "1".to_string() };
+        let key: QueryKey = ("items:get".to_string(), args.clone().into_iter().collect());
+        let value: CacheSlot = Arc::new(Mutex::new(None));
+        let subscriber = CacheSubscriber::new(value.clone(), client.core.write_generation.clone());
+        client.core.prefetched.lock().insert(
+            key,
+            PrefetchEntry {
+                value,
+                handle: Arc::new(SubscriptionHandle::new(7, CancellationToken::new())),
+            },
+        );
+        subscriber.on_update("1".to_string());
+        assert_eq!(client.core.cached_result("items:get", &args), Some("1".to_string()));
+
+        // The subscription loop is waiting for its next value when a mutation
+        // resolves; the value it then stores may predate the write.
+        client.core.invalidate_prefetched();
+        assert_eq!(client.core.cached_result("items:get", &args), None);
+        subscriber.on_update("stale".to_string());
+        assert_eq!(client.core.cached_result("items:get", &args), None);
+
+        subscriber.on_update("2".to_string());
+        assert_eq!(client.core.cached_result("items:get", &args), Some("2".to_string()));
+    }
+
+    #[tokio::test]
+    async fn subscription_cap_evicts_prefetches_first() {
+        let client = MobileConvexClient::new_with_runtime("url".into(), "id".into(), Handle::current());
+        let evicted = Arc::new(Mutex::new(Vec::new()));
+        let reported = evicted.clone();
+        client.set_max_subscriptions(0, move |id, _name| {
+            reported.lock().push(id);
+            Box::pin(async {})
+        });
+        let subscribe = |id: u64, prefetch: bool| {
+            let handle = Arc::new(SubscriptionHandle::new(id, CancellationToken::new()));
+            client.core.register_subscription("q", &handle, prefetch, &client.state);
+            handle
+        };
+        let regular_old = subscribe(1, false);
+        let prefetched = subscribe(2, true);
+        let regular_new = subscribe(3, false);
+
+        // Lowering the cap evicts immediately, prefetches first.
+        client.core.max_subscriptions.store(2, Ordering::Relaxed);
+        client.core.enforce_subscription_limit(None);
+        assert!(prefetched.cancel_token.is_cancelled());
+        assert!(!regular_old.cancel_token.is_cancelled());
+
+        // A prefetch over the cap evicts itself rather than a regular subscription.
+        let extra_prefetch = subscribe(4, true);
+        assert!(extra_prefetch.cancel_token.is_cancelled());
+        assert!(!regular_old.cancel_token.is_cancelled());
+
+        // A regular subscription over the cap evicts the least recently updated one.
+        client.core.touch_subscription(1);
+        let newest = subscribe(5, false);
+        assert!(regular_new.cancel_token.is_cancelled());
+        assert!(!regular_old.cancel_token.is_cancelled());
+        assert!(!newest.cancel_token.is_cancelled());
+
+        tokio::task::yield_now().await;
+        assert_eq!(*evicted.lock(), vec![3]);
+    }
+
+    #[tokio::test]
+    async fn evictions_are_reported_to_the_owning_engine() {
+        let core = Arc::new(ClientCore::with_runtime("url".into(), "id".into(), Handle::current(), None));
+        let engine_a = MobileConvexClient::with_core(core.clone(), None);
+        let engine_b = MobileConvexClient::with_core(core.clone(), None);
+        let evicted = Arc::new(Mutex::new(Vec::new()));
+        for (engine, label) in [(&engine_a, "a"), (&engine_b, "b")] {
+            let reported = evicted.clone();
+            engine.set_max_subscriptions(1, move |id, _name| {
+                reported.lock().push((label, id));
+                Box::pin(async {})
+            });
+        }
+        let handle_a = Arc::new(SubscriptionHandle::new(1, CancellationToken::new()));
+        core.register_subscription("q", &handle_a, false, &engine_a.state);
+        let handle_b = Arc::new(SubscriptionHandle::new(2, CancellationToken::new()));
+        core.register_subscription("q", &handle_b, false, &engine_b.state);
+        assert!(handle_a.cancel_token.is_cancelled());
+        tokio::task::yield_now().await;
+        assert_eq!(*evicted.lock(), vec![("a", 1)]);
+    }
+
+    #[tokio::test]
+    async fn traffic_and_budgets_are_kept_per_engine() {
+        let core = Arc::new(ClientCore::with_runtime("url".into(), "id".into(), Handle::current(), None));
+        core.engines.lock().entry("a".to_string()).or_default().count += 1;
+        let engine_a = MobileConvexClient::with_core(core.clone(), Some("a".to_string()));
+        let engine_b = MobileConvexClient::with_core(core.clone(), None);
+        let exceeded = Arc::new(Mutex::new(Vec::new()));
+        for (engine, label) in [(&engine_a, "a"), (&engine_b, "b")] {
+            let reported = exceeded.clone();
+            engine.set_data_budget(10, move |total| {
+                reported.lock().push((label, total));
+                Box::pin(async {})
+            });
+        }
+        engine_b.state.record_call(CallDirection::Incoming, CallKind::SubscriptionUpdated, "q", 20, String::new);
+        tokio::task::yield_now().await;
+        assert_eq!(*exceeded.lock(), vec![("b", 20)]);
+        assert!(engine_a.traffic_stats().is_empty());
+
+        engine_a.state.record_call(CallDirection::Outgoing, CallKind::QueryCalled, "q", 5, String::new);
+        engine_b.reset_traffic_stats();
+        assert_eq!(engine_a.traffic_stats().len(), 1);
+
+        // A detached engine's budget callback is dropped with the attachment.
+        engine_a.detach();
+        engine_a.state.record_call(CallDirection::Incoming, CallKind::QueryReturned, "q", 20, String::new);
+        tokio::task::yield_now().await;
+        assert_eq!(*exceeded.lock(), vec![("b", 20)]);
+    }
+
+    #[cfg(debug_assertions)]
+    #[tokio::test]
+    async fn call_taps_only_see_their_own_handle() {
+        let core = Arc::new(ClientCore::with_runtime("url".into(), "id".into(), Handle::current(), None));
+        core.engines.lock().entry("a".to_string()).or_default().count += 1;
+        let engine_a = MobileConvexClient::with_core(core.clone(), Some("a".to_string()));
+        let engine_b = MobileConvexClient::with_core(core, None);
+        let tapped = Arc::new(Mutex::new(Vec::new()));
+        let reported = tapped.clone();
+        engine_a.set_call_tap(move |call| {
+            reported.lock().push((call.kind, call.function_name));
+            Box::pin(async {})
+        });
+        engine_b.state.record_call(CallDirection::Outgoing, CallKind::QueryCalled, "b", 2, || "{}".to_string());
+        engine_a.state.record_call(CallDirection::Outgoing, CallKind::MutationCalled, "a", 2, || "{}".to_string());
+        engine_a.detach();
+        engine_a.state.record_call(CallDirection::Incoming, CallKind::MutationReturned, "a", 4, || "null".to_string());
+        tokio::task::yield_now().await;
+        assert_eq!(*tapped.lock(), vec![(CallKind::MutationCalled, "a".to_string())]);
+    }
+
+    #[tokio::test]
+    async fn data_budget_fires_once_on_the_running_total() {
+        let client = MobileConvexClient::new_with_runtime("url".into(), "id".into(), Handle::current());
+        let exceeded = Arc::new(Mutex::new(Vec::new()));
+        let reported = exceeded.clone();
+        client.set_data_budget(100, move |total| {
+            reported.lock().push(total);
+            Box::pin(async {})
+        });
+        client.state.record_call(CallDirection::Outgoing, CallKind::Subscribed, "a", 40, String::new);
+        client.state.record_call(CallDirection::Incoming, CallKind::SubscriptionUpdated, "b", 50, String::new);
+        client.state.record_call(CallDirection::Incoming, CallKind::SubscriptionUpdated, "a", 20, String::new);
+        client.state.record_call(CallDirection::Incoming, CallKind::SubscriptionUpdated, "a", 20, String::new);
+        tokio::task::yield_now().await;
+        assert_eq!(*exceeded.lock(), vec![110]);
+
+        let stats = client.traffic_stats();
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[0].function_name, "a");
+        assert_eq!((stats[0].bytes_sent, stats[0].bytes_received), (40, 40));
+        assert_eq!((stats[0].messages_sent, stats[0].messages_received), (1, 2));
+
+        client.reset_traffic_stats();
+        assert!(client.traffic_stats().is_empty());
+        client.state.record_call(CallDirection::Incoming, CallKind::SubscriptionUpdated, "a", 101, String::new);
+        tokio::task::yield_now().await;
+        assert_eq!(*exceeded.lock(), vec![110, 101]);
+    }
+
+    #[test]
+    fn rate_limit_codes_are_recognized() {
+        assert_eq!(parse_rate_limit("RateLimited: slow down"), Some(None));
+        assert_eq!(parse_rate_limit("[Request ID: 1] Overloaded"), Some(None));
+        assert_eq!(parse_rate_limit("TooManyConcurrentRequests"), Some(None));
+        assert_eq!(parse_rate_limit("TooManyRequests (429)"), Some(None));
+    }
+
+    #[test]
+    fn free_text_is_not_a_rate_limit() {
+        assert_eq!(parse_rate_limit("Server Error"), None);
+        assert_eq!(parse_rate_limit("rate limit exceeded for this user"), None);
+        assert_eq!(parse_rate_limit("the queue is overloaded"), None);
+        assert_eq!(parse_rate_limit("NotRateLimitedAtAll"), None);
+    }
+
+    #[test]
+    fn function_errors_are_never_rate_limits() {
+        assert_eq!(
+            parse_rate_limit("Server Error\nUncaught Error: RateLimited, retry after 1s"),
+            None
+        );
+    }
+
+    #[test]
+    fn retry_after_units() {
+        let parse = |msg: &str| parse_rate_limit(msg).flatten();
+        assert_eq!(parse("RateLimited, retry after 250ms"), Some(250));
+        assert_eq!(parse("RateLimited, retry after 250 milliseconds"), Some(250));
+        assert_eq!(parse("Overloaded. Retry-After: 3"), Some(3_000));
+        assert_eq!(parse("Overloaded. Retry-After: 3, please"), Some(3_000));
+        assert_eq!(parse("RateLimited retryAfter=4s"), Some(4_000));
+        assert_eq!(parse("RateLimited, retry after 5 seconds"), Some(5_000));
+        assert_eq!(parse("RateLimited, retry after 2 minutes"), Some(120_000));
+        assert_eq!(parse("RateLimited, retry after 2 min"), Some(120_000));
+    }
+
+    #[test]
+    fn retry_after_without_a_usable_delay() {
+        assert_eq!(parse_rate_limit("RateLimited, retry after 2 hours"), Some(None));
+        assert_eq!(parse_rate_limit("RateLimited, retry after a while"), Some(None));
+        assert_eq!(parse_rate_limit("RateLimited, retry later"), Some(None));
+    }
+}
@@ -0,0 +1,309 @@
+#[cfg(feature = "transforms")]
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, HashMap},
+    sync::Arc,
+};
+
+use flutter_rust_bridge::frb;
+#[cfg(feature = "transforms")]
+use once_cell::sync::Lazy;
+#[cfg(feature = "transforms")]
+use parking_lot::RwLock;
+#[cfg(feature = "transforms")]
+use serde_json::{Map, Value as JsonValue};
+
+// The Dart-facing `SubscriptionTransform` is always compiled so the generated
+// bindings match every build; only the transform engine is feature-gated.
+
+/// Signature of a transform plugin implemented in Rust.
+/// Returning `None` drops the update instead of forwarding it to Dart.
+#[cfg(feature = "transforms")]
+pub type TransformFn = Arc<dyn Fn(JsonValue) -> Option<JsonValue> + Send + Sync>;
+
+// Registry of named plugin transforms, shared by every client in the process.
+#[cfg(feature = "transforms")]
+static PLUGINS: Lazy<RwLock<HashMap<String, TransformFn>>> = Lazy::new(Default::default);
+
+/// A reshaping step applied to each subscription value on the Rust side,
+/// before it is serialized and handed to Dart.
+#[derive(Debug, Clone)]
+#[frb]
+pub enum SubscriptionTransform {
+    /// Sorts an array of objects by the value stored under `key`.
+    SortBy { key: String, descending: bool },
+    /// Groups an array of objects by the value stored under `key`, producing an
+    /// array of `{"key": value, "items": [...]}` in order of first appearance.
+    /// Keys keep their JSON type, so `7` and `"7"` form separate groups; a
+    /// missing key groups with `null`.
+    GroupBy { key: String },
+    /// Keeps only the first `count` elements of an array.
+    Take { count: u32 },
+    /// Runs a plugin previously registered with [`register_transform`] by Rust
+    /// code linking this crate with the `transforms` feature.
+    Plugin { name: String },
+}
+
+/// Registers a named plugin transform that Dart can refer to through
+/// [`SubscriptionTransform::Plugin`]. Replaces any plugin with the same name.
+///
+/// Called from Rust code that depends on this crate, e.g. another plugin's
+/// native library, before Dart subscribes with the plugin.
+#[cfg(feature = "transforms")]
+#[frb(ignore)]
+pub fn register_transform(name: impl Into<String>, transform: TransformFn) {
+    PLUGINS.write().insert(name.into(), transform);
+}
+
+/// Removes a previously registered plugin transform.
+#[cfg(feature = "transforms")]
+#[frb(ignore)]
+pub fn unregister_transform(name: &str) {
+    PLUGINS.write().remove(name);
+}
+
+/// Applies a chain of transforms in order. `Ok(None)` means a plugin filtered
+/// the value out and no update should be delivered.
+#[cfg(feature = "transforms")]
+pub(crate) fn apply_transforms(
+    transforms: &[SubscriptionTransform],
+    mut value: JsonValue,
+) -> Result<Option<JsonValue>, String> {
+    for transform in transforms {
+        value = match transform {
+            SubscriptionTransform::SortBy { key, descending } => {
+                let mut items = expect_array(value, "SortBy")?;
+                items.sort_by(|a, b| {
+                    let ordering = compare_json(a.get(key), b.get(key));
+                    if *descending {
+                        ordering.reverse()
+                    } else {
+                        ordering
+                    }
+                });
+                JsonValue::Array(items)
+            }
+            SubscriptionTransform::GroupBy { key } => {
+                let mut groups: Vec<(JsonValue, Vec<JsonValue>)> = Vec::new();
+                // Indexed by the key's JSON encoding, which keeps types apart.
+                let mut index: HashMap<String, usize> = HashMap::new();
+                for item in expect_array(value, "GroupBy")? {
+                    let group = item.get(key).cloned().unwrap_or(JsonValue::Null);
+                    match index.entry(group.to_string()) {
+                        Entry::Occupied(entry) => groups[*entry.get()].1.push(item),
+                        Entry::Vacant(entry) => {
+                            entry.insert(groups.len());
+                            groups.push((group, vec![item]));
+                        }
+                    }
+                }
+                JsonValue::Array(
+                    groups
+                        .into_iter()
+                        .map(|(key, items)| {
+                            let mut group = Map::new();
+                            group.insert("key".to_string(), key);
+                            group.insert("items".to_string(), JsonValue::Array(items));
+                            JsonValue::Object(group)
+                        })
+                        .collect(),
+                )
+            }
+            SubscriptionTransform::Take { count } => {
+                let mut items = expect_array(value, "Take")?;
+                items.truncate(*count as usize);
+                JsonValue::Array(items)
+            }
+            SubscriptionTransform::Plugin { name } => {
+                let plugin = PLUGINS
+                    .read()
+                    .get(name)
+                    .cloned()
+                    .ok_or_else(|| format!("Unknown transform plugin: {name}"))?;
+                match plugin(value) {
+                    Some(value) => value,
+                    None => return Ok(None),
+                }
+            }
+        };
+    }
+    Ok(Some(value))
+}
+
+#[cfg(feature = "transforms")]
+fn expect_array(value: JsonValue, step: &str) -> Result<Vec<JsonValue>, String> {
+    match value {
+        JsonValue::Array(items) => Ok(items),
+        other => Err(format!("{step} transform expects an array, got {other}")),
+    }
+}
+
+#[cfg(feature = "transforms")]
+/// Total order over JSON values used for sorting: missing/null < bool < number < string.
+fn compare_json(a: Option<&JsonValue>, b: Option<&JsonValue>) -> Ordering {
+    fn rank(value: Option<&JsonValue>) -> u8 {
+        match value {
+            None | Some(JsonValue::Null) => 0,
+            Some(JsonValue::Bool(_)) => 1,
+            Some(JsonValue::Number(_)) => 2,
+            Some(JsonValue::String(_)) => 3,
+            Some(JsonValue::Array(_)) => 4,
+            Some(JsonValue::Object(_)) => 5,
+        }
+    }
+    match (a, b) {
+        (Some(JsonValue::Bool(x)), Some(JsonValue::Bool(y))) => x.cmp(y),
+        (Some(JsonValue::Number(x)), Some(JsonValue::Number(y))) => x
+            .as_f64()
+            .partial_cmp(&y.as_f64())
+            .unwrap_or(Ordering::Equal),
+        (Some(JsonValue::String(x)), Some(JsonValue::String(y))) => x.cmp(y),
+        _ => rank(a).cmp(&rank(b)),
+    }
+}
+
+#[cfg(all(test, feature = "transforms"))]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn sort_by(key: &str, descending: bool) -> SubscriptionTransform {
+        SubscriptionTransform::SortBy {
+            key: key.to_string(),
+            descending,
+        }
+    }
+
+    #[test]
+    fn sort_orders_mixed_types() {
+        let value = json!([
+            {"k": "b"},
+            {"k": 2},
+            {},
+            {"k": true},
+            {"k": null},
+            {"k": "a"},
+            {"k": 1.5},
+            {"k": false},
+        ]);
+        let sorted = apply_transforms(&[sort_by("k", false)], value).unwrap().unwrap();
+        assert_eq!(
+            sorted,
+            json!([
+                {},
+                {"k": null},
+                {"k": false},
+                {"k": true},
+                {"k": 1.5},
+                {"k": 2},
+                {"k": "a"},
+                {"k": "b"},
+            ])
+        );
+    }
+
+    #[test]
+    fn sort_descending_is_stable_reverse() {
+        let value = json!([{"k": 1, "i": 0}, {"k": 3}, {"k": 1, "i": 1}]);
+        let sorted = apply_transforms(&[sort_by("k", true)], value).unwrap().unwrap();
+        assert_eq!(sorted, json!([{"k": 3}, {"k": 1, "i": 0}, {"k": 1, "i": 1}]));
+    }
+
+    #[test]
+    fn sort_rejects_non_arrays() {
+        assert!(apply_transforms(&[sort_by("k", false)], json!({"k": 1})).is_err());
+    }
+
+    #[test]
+    fn group_by_key() {
+        let value = json!([
+            {"team": "red", "n": 1},
+            {"team": "blue", "n": 2},
+            {"team": "red", "n": 3},
+            {"n": 4},
+            {"team": null, "n": 5},
+        ]);
+        let transform = SubscriptionTransform::GroupBy {
+            key: "team".to_string(),
+        };
+        let grouped = apply_transforms(&[transform], value).unwrap().unwrap();
+        assert_eq!(
+            grouped,
+            json!([
+                {"key": "red", "items": [{"team": "red", "n": 1}, {"team": "red", "n": 3}]},
+                {"key": "blue", "items": [{"team": "blue", "n": 2}]},
+                {"key": null, "items": [{"n": 4}, {"team": null, "n": 5}]},
+            ])
+        );
+    }
+
+    #[test]
+    fn group_by_keeps_key_types_apart() {
+        let value = json!([
+            {"k": 7},
+            {"k": "7"},
+            {"k": true},
+            {"k": "true"},
+            {"k": 7},
+        ]);
+        let transform = SubscriptionTransform::GroupBy { key: "k".to_string() };
+        let grouped = apply_transforms(&[transform], value).unwrap().unwrap();
+        assert_eq!(
+            grouped,
+            json!([
+                {"key": 7, "items": [{"k": 7}, {"k": 7}]},
+                {"key": "7", "items": [{"k": "7"}]},
+                {"key": true, "items": [{"k": true}]},
+                {"key": "true", "items": [{"k": "true"}]},
+            ])
+        );
+    }
+
+    #[test]
+    fn take_truncates_after_sorting() {
+        let transforms = [sort_by("k", true), SubscriptionTransform::Take { count: 2 }];
+        let value = json!([{"k": 1}, {"k": 3}, {"k": 2}]);
+        let taken = apply_transforms(&transforms, value).unwrap().unwrap();
+        assert_eq!(taken, json!([{"k": 3}, {"k": 2}]));
+    }
+
+    #[test]
+    fn take_more_than_available_keeps_everything() {
+        let transform = SubscriptionTransform::Take { count: 10 };
+        let taken = apply_transforms(&[transform], json!([1, 2])).unwrap().unwrap();
+        assert_eq!(taken, json!([1, 2]));
+    }
+
+    #[test]
+    fn unknown_plugin_is_an_error() {
+        let transform = SubscriptionTransform::Plugin {
+            name: "tests::missing".to_string(),
+        };
+        let error = apply_transforms(&[transform], json!([])).unwrap_err();
+        assert!(error.contains("tests::missing"));
+    }
+
+    #[test]
+    fn plugin_returning_none_filters_the_update() {
+        register_transform(
+            "tests::drop_empty",
+            Arc::new(|value| match &value {
+                JsonValue::Array(items) if items.is_empty() => None,
+                _ => Some(value),
+            }),
+        );
+        let transforms = [
+            SubscriptionTransform::Plugin {
+                name: "tests::drop_empty".to_string(),
+            },
+            SubscriptionTransform::Take { count: 1 },
+        ];
+        assert_eq!(apply_transforms(&transforms, json!([])).unwrap(), None);
+        assert_eq!(
+            apply_transforms(&transforms, json!([1, 2])).unwrap(),
+            Some(json!([1]))
+        );
+        unregister_transform("tests::drop_empty");
+    }
+}